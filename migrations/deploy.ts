@@ -49,6 +49,15 @@ module.exports = async function (provider: anchor.AnchorProvider) {
     [Buffer.from("platform_config")],
     program.programId
   );
+  const [platformStats] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_stats")],
+    program.programId
+  );
+
+  const [protocolParams] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_params")],
+    program.programId
+  );
 
   console.log("   Platform Config PDA:", platformConfig.toString());
 
@@ -58,14 +67,14 @@ module.exports = async function (provider: anchor.AnchorProvider) {
     console.log("\n✅ Platform config already initialized!");
     console.log("   Admin:", existingConfig.admin.toString());
     console.log("   Fee Collector:", existingConfig.feeCollector.toString());
-    console.log("   Paused:", existingConfig.paused);
+    console.log("   Paused Modules:", existingConfig.pausedModules);
     console.log("   Min Liquidity BPS:", existingConfig.minLiquidityBps.toString(), "(10%)");
     
     if (!existingConfig.admin.equals(ADMIN_WALLET)) {
       console.log("\n⚠️  WARNING: Admin mismatch!");
       console.log("   Expected:", ADMIN_WALLET.toString());
       console.log("   Actual:", existingConfig.admin.toString());
-      console.log("   Run 'update_admin' instruction to change admin");
+      console.log("   Run 'propose_admin' then 'accept_admin' to change admin");
     }
   } catch (e) {
     // Not initialized yet - proceed with initialization
@@ -90,6 +99,8 @@ module.exports = async function (provider: anchor.AnchorProvider) {
       .initializePlatform(FEE_COLLECTOR)
       .accountsPartial({
         platformConfig,
+        platformStats,
+        protocolParams,
         admin: ADMIN_WALLET,  // Admin is set to configured address
         systemProgram: SystemProgram.programId,
       })
@@ -109,6 +120,8 @@ module.exports = async function (provider: anchor.AnchorProvider) {
   console.log("✅ Platform config initialized");
   console.log("✅ Admin wallet:", ADMIN_WALLET.toString());
   console.log("✅ Fee collector:", FEE_COLLECTOR.toString());
+  console.log("✅ Platform stats initialized");
+  console.log("✅ Protocol params initialized");
   console.log("\n⚠️  SECURITY REMINDERS:");
   console.log("   - Admin wallet controls pause/unpause");
   console.log("   - Admin can change fee collector");
@@ -18,6 +18,15 @@ async function main() {
     [Buffer.from('platform_config')],
     program.programId
   );
+  const [platformStats] = PublicKey.findProgramAddressSync(
+    [Buffer.from('platform_stats')],
+    program.programId
+  );
+
+  const [protocolParams] = PublicKey.findProgramAddressSync(
+    [Buffer.from('protocol_params')],
+    program.programId
+  );
 
   console.log('Platform config PDA:', platformConfig.toBase58());
 
@@ -27,7 +36,7 @@ async function main() {
     console.log('✅ Platform already initialized');
     console.log('Admin:', account.admin.toBase58());
     console.log('Fee collector:', account.feeCollector.toBase58());
-    console.log('Paused:', account.paused);
+    console.log('Paused Modules:', account.pausedModules);
     return;
   } catch (error) {
     console.log('Platform not initialized, initializing...');
@@ -39,6 +48,8 @@ async function main() {
       .initializePlatform(provider.wallet.publicKey)
       .accountsPartial({
         platformConfig,
+        platformStats,
+        protocolParams,
         admin: provider.wallet.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -52,11 +63,45 @@ async function main() {
     console.log('\nPlatform Config:');
     console.log('Admin:', account.admin.toBase58());
     console.log('Fee collector:', account.feeCollector.toBase58());
-    console.log('Paused:', account.paused);
+    console.log('Paused Modules:', account.pausedModules);
   } catch (error) {
     console.error('❌ Error initializing platform:', error);
     throw error;
   }
+
+  // Platforms initialized before protocol params existed create them separately
+  try {
+    await program.account.protocolParams.fetch(protocolParams);
+    console.log('✅ Protocol params already initialized');
+  } catch (error) {
+    const tx = await program.methods
+      .initializeProtocolParams()
+      .accountsPartial({
+        platformConfig,
+        protocolParams,
+        admin: provider.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    console.log('✅ Protocol params initialized:', tx);
+  }
+
+  // Platforms initialized before stats existed create them separately
+  try {
+    await program.account.platformStats.fetch(platformStats);
+    console.log('✅ Platform stats already initialized');
+  } catch (error) {
+    const tx = await program.methods
+      .initializePlatformStats()
+      .accountsPartial({
+        platformConfig,
+        platformStats,
+        admin: provider.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    console.log('✅ Platform stats initialized:', tx);
+  }
 }
 
 main()
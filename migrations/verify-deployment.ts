@@ -46,7 +46,7 @@ async function main() {
     console.log('✅ Platform config initialized');
     console.log('   Admin:', account.admin.toBase58());
     console.log('   Fee Collector:', account.feeCollector.toBase58());
-    console.log('   Paused:', account.paused);
+    console.log('   Paused Modules:', account.pausedModules);
     console.log('   Bump:', account.bump);
   } catch (error) {
     console.log('⚠️  Platform config not initialized');
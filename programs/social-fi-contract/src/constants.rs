@@ -17,18 +17,38 @@ pub const FOLLOW_SEED: &[u8] = b"follow";
 pub const LIKE_SEED: &[u8] = b"like";
 pub const REPOST_SEED: &[u8] = b"repost";
 pub const COMMENT_SEED: &[u8] = b"comment";
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+pub const MULTISIG_VAULT_SEED: &[u8] = b"multisig_vault";
+pub const MULTISIG_TRANSACTION_SEED: &[u8] = b"multisig_transaction";
+pub const PROTOCOL_PARAMS_SEED: &[u8] = b"protocol_params";
+pub const BLOCKED_SEED: &[u8] = b"blocked";
+pub const PLATFORM_STATS_SEED: &[u8] = b"platform_stats";
+pub const REFERRAL_CODE_SEED: &[u8] = b"referral_code";
+pub const REFERRAL_REWARDS_SEED: &[u8] = b"referral_rewards";
+pub const USERNAME_REGISTRY_SEED: &[u8] = b"username_registry";
+pub const USERNAME_RESOLVER_SEED: &[u8] = b"username_resolver";
+pub const TIP_MINT_SEED: &[u8] = b"tip_mint";
+pub const TIP_STREAM_SEED: &[u8] = b"tip_stream";
+pub const SESSION_KEY_SEED: &[u8] = b"session_key";
+pub const ATTESTER_SEED: &[u8] = b"attester";
+pub const VERIFICATION_SEED: &[u8] = b"verification";
+pub const LINKED_IDENTITY_SEED: &[u8] = b"linked_identity";
+pub const BLOCK_SEED: &[u8] = b"block";
+
+// Account Versioning
+pub const ACCOUNT_VERSION: u8 = 1; // Layout version written by every init path
 
 // Bonding Curve Constants
-pub const BASE_PRICE: u64 = 10_000_000; // 0.01 SOL in lamports
+pub const BASE_PRICE: u64 = 10_000_000; // 0.01 SOL in lamports (default ProtocolParams.base_price)
 pub const PRICE_SCALE: u64 = 100; // Scale factor for bonding curve
-pub const SELL_FEE_BPS: u64 = 1000; // 10% in basis points
+pub const SELL_FEE_BPS: u64 = 1000; // 10% in basis points (default share sell fee)
 pub const MAX_SUPPLY: u64 = 1_000_000; // Maximum supply to prevent overflow
 pub const MAX_PRICE: u64 = u64::MAX / 1000; // Max price cap
 
 // Governance Constants
-pub const MIN_VOTING_POWER: u64 = 1000;
+pub const MIN_VOTING_POWER: u64 = 1000; // default ProtocolParams.min_voting_power
 pub const QUORUM_BPS: u64 = 1000; // 10% in basis points
-pub const VOTING_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+pub const VOTING_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days in seconds (default ProtocolParams.voting_period)
 pub const MIN_EXECUTION_DELAY: i64 = 24 * 60 * 60; // 24 hours
 
 // Lock Period Constants (in days)
@@ -37,15 +57,23 @@ pub const LOCK_30_DAYS: u64 = 30;
 pub const LOCK_90_DAYS: u64 = 90;
 pub const LOCK_180_DAYS: u64 = 180;
 pub const LOCK_365_DAYS: u64 = 365;
+pub const LOCK_PERIODS: [u64; LOCK_TIER_COUNT] = [
+    LOCK_0_DAYS,
+    LOCK_30_DAYS,
+    LOCK_90_DAYS,
+    LOCK_180_DAYS,
+    LOCK_365_DAYS,
+];
+pub const LOCK_TIER_COUNT: usize = 5;
 
-// Voting Power Multipliers (in basis points)
+// Voting Power Multipliers (in basis points, defaults for ProtocolParams.lock_multipliers_bps)
 pub const MULTIPLIER_0_DAYS: u64 = 10000; // 1.0x
 pub const MULTIPLIER_30_DAYS: u64 = 12000; // 1.2x
 pub const MULTIPLIER_90_DAYS: u64 = 15000; // 1.5x
 pub const MULTIPLIER_180_DAYS: u64 = 20000; // 2.0x
 pub const MULTIPLIER_365_DAYS: u64 = 30000; // 3.0x
 
-// APY Rates (in basis points)
+// APY Rates (in basis points, defaults for ProtocolParams.apy_bps)
 pub const APY_0_DAYS: u64 = 500; // 5%
 pub const APY_30_DAYS: u64 = 1000; // 10%
 pub const APY_90_DAYS: u64 = 1500; // 15%
@@ -54,12 +82,82 @@ pub const APY_365_DAYS: u64 = 3000; // 30%
 
 // String Limits
 pub const MAX_USERNAME_LENGTH: usize = 20;
+pub const MAX_REFERRAL_CODE_LENGTH: usize = 10;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 32;
+pub const MAX_BIO_LENGTH: usize = 160;
+pub const MAX_URI_LENGTH: usize = 200;
+pub const MAX_WEBSITE_LENGTH: usize = 100;
 pub const MAX_TITLE_LENGTH: usize = 32; // Metaplex NFT name limit
 pub const MAX_DESCRIPTION_LENGTH: usize = 500;
+pub const MAX_TIP_MESSAGE_LENGTH: usize = 140;
+/// Recipients per `send_tips_batch`. Each entry costs about 175 bytes (its `BatchTip` plus
+/// four remaining account keys), so four is the most a legacy 1232-byte transaction holds
+/// next to the fixed accounts.
+pub const MAX_TIP_BATCH_SIZE: usize = 4;
+pub const MIN_TIP_STREAM_PERIOD: i64 = 60 * 60; // 1 hour
 pub const MAX_NAME_LENGTH: usize = 50;
 
 // Marketplace Constants
-pub const MIN_OFFER_AMOUNT: u64 = 100_000; // 0.0001 SOL minimum offer
+pub const MIN_OFFER_AMOUNT: u64 = 100_000; // 0.0001 SOL minimum offer (default ProtocolParams.min_offer_amount)
+
+// Protocol Parameter Bounds (enforced by the admin setters)
+pub const MIN_BASE_PRICE: u64 = 1_000; // 0.000001 SOL
+pub const MAX_BASE_PRICE: u64 = 1_000_000_000; // 1 SOL
+pub const MAX_MIN_OFFER_AMOUNT: u64 = 1_000_000_000; // 1 SOL
+pub const MIN_VOTING_PERIOD: i64 = 24 * 60 * 60; // 1 day
+pub const MAX_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const MAX_MIN_VOTING_POWER: u64 = 1_000_000_000;
+pub const MAX_LOCK_MULTIPLIER_BPS: u64 = 50000; // 5.0x
+pub const MAX_APY_BPS: u64 = 5000; // 50%
+
+// Platform Fee Defaults (in basis points)
+pub const DEFAULT_SHARE_BUY_FEE_BPS: u64 = 0;
+pub const DEFAULT_TIP_FEE_BPS: u64 = 100; // 1%
+pub const DEFAULT_SUBSCRIPTION_FEE_BPS: u64 = 500; // 5%
+pub const DEFAULT_GROUP_ENTRY_FEE_BPS: u64 = 500; // 5%
+pub const DEFAULT_MARKETPLACE_FEE_BPS: u64 = 250; // 2.5%
+pub const MAX_PLATFORM_FEE_BPS: u64 = 2000; // 20% cap on any single stream
+
+// Referral Constants (share of the platform fee, in basis points)
+pub const DEFAULT_REFERRAL_SHARE_BPS: u64 = 1000; // 10% of the fee goes to the referrer
+pub const MAX_REFERRAL_SHARE_BPS: u64 = 5000; // 50%
+
+// Pause Flags (bitmask stored in PlatformConfig.paused_modules)
+pub const PAUSE_SHARES: u8 = 1 << 0;
+pub const PAUSE_SUBSCRIPTIONS: u8 = 1 << 1;
+pub const PAUSE_GROUPS: u8 = 1 << 2;
+pub const PAUSE_GOVERNANCE: u8 = 1 << 3;
+pub const PAUSE_MARKETPLACE: u8 = 1 << 4;
+pub const PAUSE_POSTS: u8 = 1 << 5;
+pub const PAUSE_SOCIAL: u8 = 1 << 6;
+pub const PAUSE_TIPS: u8 = 1 << 7;
+pub const PAUSE_ALL: u8 = u8::MAX;
+
+// Session key scopes (bitmask)
+pub const SESSION_SCOPE_FOLLOW: u8 = 1 << 0;
+pub const SESSION_SCOPE_LIKE: u8 = 1 << 1;
+pub const SESSION_SCOPE_COMMENT: u8 = 1 << 2;
+pub const SESSION_SCOPE_REPOST: u8 = 1 << 3;
+pub const SESSION_SCOPE_ALL: u8 = SESSION_SCOPE_FOLLOW | SESSION_SCOPE_LIKE | SESSION_SCOPE_COMMENT | SESSION_SCOPE_REPOST;
+pub const MAX_SESSION_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days
+
+// Verification kinds
+pub const VERIFICATION_KIND_IDENTITY: u8 = 0;
+pub const VERIFICATION_KIND_ORGANIZATION: u8 = 1;
+pub const VERIFICATION_KIND_CREATOR: u8 = 2;
+
+// Linked identity kinds
+pub const IDENTITY_KIND_SOCIAL: u8 = 0;
+pub const IDENTITY_KIND_DOMAIN: u8 = 1;
+pub const MAX_IDENTITY_HANDLE_LENGTH: usize = 64;
+
+// Platform Admin Constants
+pub const ADMIN_TRANSFER_DELAY: i64 = 48 * 60 * 60; // 48 hours before a proposed admin can accept
+
+// Multisig Constants
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_MULTISIG_TX_ACCOUNTS: usize = 16;
+pub const MAX_MULTISIG_TX_DATA: usize = 512;
 
 // Time Constants
 pub const SECONDS_PER_DAY: i64 = 86400;
@@ -166,4 +166,178 @@ pub enum SocialFiError {
     
     #[msg("Content cannot be empty")]
     EmptyContent,
+    
+    #[msg("Invalid pending admin")]
+    InvalidPendingAdmin,
+    
+    #[msg("No admin transfer pending")]
+    NoPendingAdmin,
+    
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    
+    #[msg("Admin transfer timelock has not elapsed")]
+    AdminTransferTimelocked,
+    
+    #[msg("Pause flags must select at least one module")]
+    InvalidPauseFlags,
+    
+    #[msg("Fee exceeds maximum allowed rate")]
+    FeeTooHigh,
+    
+    #[msg("Fee collector does not match platform config")]
+    InvalidFeeCollector,
+    
+    #[msg("Invalid multisig signer set")]
+    InvalidMultisigSigners,
+    
+    #[msg("Invalid multisig threshold")]
+    InvalidMultisigThreshold,
+    
+    #[msg("Signer is not a member of this multisig")]
+    NotMultisigSigner,
+    
+    #[msg("Signer already approved this transaction")]
+    AlreadyApproved,
+    
+    #[msg("Multisig approval threshold not met")]
+    MultisigThresholdNotMet,
+    
+    #[msg("Multisig transaction already executed")]
+    TransactionAlreadyExecuted,
+    
+    #[msg("Invalid multisig transaction")]
+    InvalidMultisigTransaction,
+    
+    #[msg("Multisig signer set changed since this transaction was proposed")]
+    StaleMultisigTransaction,
+    
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
+    
+    #[msg("Account type does not support migration")]
+    UnsupportedAccountType,
+    
+    #[msg("Guardian must differ from the admin")]
+    InvalidGuardian,
+    
+    #[msg("Protocol parameter out of allowed range")]
+    ProtocolParamOutOfRange,
+    
+    #[msg("Wallet is on the platform blocklist")]
+    WalletBlocked,
+    
+    #[msg("Unknown referral code")]
+    InvalidReferralCode,
+    
+    #[msg("Cannot use your own referral code")]
+    SelfReferral,
+    
+    #[msg("No referral rewards to claim")]
+    NoReferralRewards,
+    
+    #[msg("Rewards account of the payer's referrer is required")]
+    ReferralRewardsRequired,
+    
+    #[msg("Profile field exceeds its maximum length")]
+    ProfileFieldTooLong,
+    
+    #[msg("Username mixes Latin letters with another script")]
+    MixedScriptUsername,
+    
+    #[msg("Primary username is still held by the profile owner")]
+    PrimaryUsernameStillBound,
+    
+    #[msg("Profile still has open username listings")]
+    ProfileHasOpenListings,
+    
+    #[msg("Profile still has tokens staked")]
+    ProfileHasActiveStake,
+    
+    #[msg("Profile's creator pool still has shares outstanding")]
+    ProfileHasCreatorSupply,
+    
+    #[msg("Profile still follows other users")]
+    ProfileHasFollows,
+    
+    #[msg("Tip message too long (max 140 characters)")]
+    TipMessageTooLong,
+    
+    #[msg("Tip batch is empty or exceeds the maximum size")]
+    InvalidTipBatchSize,
+    
+    #[msg("Tip batch accounts do not match the recipients")]
+    InvalidTipBatchAccounts,
+    
+    #[msg("Tip stream period is below the minimum")]
+    InvalidTipStreamPeriod,
+    
+    #[msg("No tip stream payment is due")]
+    TipStreamNotDue,
+    
+    #[msg("Tip stream escrow cannot cover a payment")]
+    TipStreamDepleted,
+    
+    #[msg("Profile still has open tip streams")]
+    ProfileHasTipStreams,
+    
+    #[msg("Signer is neither the owner nor a session key of the owner")]
+    InvalidSessionKey,
+    
+    #[msg("Session key has expired or lacks the required scope")]
+    SessionKeyNotAllowed,
+    
+    #[msg("Session key allowance cannot cover the rent")]
+    SessionAllowanceExhausted,
+    
+    #[msg("Invalid session key scope or duration")]
+    InvalidSessionParams,
+    
+    #[msg("Profile still has unrevoked session keys")]
+    ProfileHasSessionKeys,
+    
+    #[msg("Invalid verification kind")]
+    InvalidVerificationKind,
+    
+    #[msg("Verification expiry must be in the future")]
+    InvalidVerificationExpiry,
+    
+    #[msg("Profile already holds an active verification from another attester")]
+    VerificationAlreadyActive,
+    
+    #[msg("Verification is already revoked")]
+    VerificationAlreadyRevoked,
+    
+    #[msg("Verification was revoked by the admin and cannot be re-issued")]
+    VerificationRevokedByAdmin,
+    
+    #[msg("Identity oracle is not configured")]
+    IdentityOracleNotSet,
+    
+    #[msg("Missing or malformed Ed25519 identity proof")]
+    InvalidIdentityProof,
+    
+    #[msg("Identity proof was not signed by the configured oracle")]
+    IdentityOracleMismatch,
+    
+    #[msg("Identity proof does not match the wallet, kind or handle")]
+    IdentityMessageMismatch,
+    
+    #[msg("Identity proof has expired")]
+    IdentityProofExpired,
+    
+    #[msg("Invalid identity kind or handle")]
+    InvalidIdentity,
+    
+    #[msg("Cannot block yourself")]
+    CannotBlockSelf,
+    
+    #[msg("This user has blocked you")]
+    BlockedByUser,
+    
+    #[msg("Referral code already belongs to another wallet")]
+    ReferralCodeTaken,
+    
+    #[msg("Resolver of the profile's current primary username is missing or wrong")]
+    InvalidPreviousResolver,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::FeeSchedule;
 
 #[event]
 pub struct UserInitialized {
@@ -7,11 +8,128 @@ pub struct UserInitialized {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ProfileUpdated {
+    pub user: Pubkey,
+    pub display_name: String,
+    pub bio: String,
+    pub avatar_uri: String,
+    pub banner_uri: String,
+    pub website: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserProfileClosed {
+    pub user: Pubkey,
+    pub profile: Pubkey,
+    pub username: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsernameChanged {
+    pub user: Pubkey,
+    pub previous_username: String,
+    pub new_username: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserReferred {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub referral_code: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardAccrued {
+    pub referrer: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TipSent {
     pub sender: Pubkey,
     pub recipient: Pubkey,
     pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PostTipped {
+    pub post: Pubkey,
+    pub sender: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub message: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipStreamCreated {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub amount_per_period: u64,
+    pub period: i64,
+    pub deposit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipStreamPaid {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub periods: u64,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipStreamCancelled {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenTipSent {
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipMintAdded {
+    pub mint: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipMintRemoved {
+    pub mint: Pubkey,
+    pub signer: Pubkey,
     pub timestamp: i64,
 }
 
@@ -22,6 +140,7 @@ pub struct SharesPurchased {
     pub amount: u64,
     pub price: u64,
     pub total_cost: u64,
+    pub fee: u64,
     pub timestamp: i64,
 }
 
@@ -50,6 +169,7 @@ pub struct UserSubscribed {
     pub subscriber: Pubkey,
     pub creator: Pubkey,
     pub tier_id: u64,
+    pub fee: u64,
     pub start_date: i64,
     pub end_date: i64,
     pub timestamp: i64,
@@ -77,6 +197,7 @@ pub struct MemberJoined {
     pub group: Pubkey,
     pub member: Pubkey,
     pub role: u8,
+    pub fee: u64,
     pub timestamp: i64,
 }
 
@@ -144,6 +265,24 @@ pub struct ProposalExecuted {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PrimaryUsernameSet {
+    pub user: Pubkey,
+    pub profile: Pubkey,
+    pub username: String,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PrimaryUsernameCleared {
+    pub user: Pubkey,
+    pub profile: Pubkey,
+    pub mint: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct UsernameMinted {
     pub owner: Pubkey,
@@ -169,6 +308,7 @@ pub struct UsernameSold {
     pub buyer: Pubkey,
     pub username: String,
     pub price: u64,
+    pub fee: u64,
     pub timestamp: i64,
 }
 
@@ -188,6 +328,7 @@ pub struct OfferAccepted {
     pub buyer: Pubkey,
     pub listing: Pubkey,
     pub amount: u64,
+    pub fee: u64,
     pub timestamp: i64,
 }
 
@@ -215,3 +356,284 @@ pub struct PostMinted {
     pub post: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub accept_after: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a pending admin accepts; `signer` is the new admin
+#[event]
+pub struct AdminChanged {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub vault: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionProposed {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionApproved {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionExecuted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigSignersChanged {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub signer_set_seqno: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `pause_platform` and `pause_modules`
+#[event]
+pub struct PlatformPaused {
+    pub previous_paused_modules: u8,
+    pub paused_modules: u8,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `unpause_platform` and `unpause_modules`
+#[event]
+pub struct PlatformUnpaused {
+    pub previous_paused_modules: u8,
+    pub paused_modules: u8,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianChanged {
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeCollectorChanged {
+    pub previous_fee_collector: Pubkey,
+    pub new_fee_collector: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinLiquidityChanged {
+    pub previous_min_liquidity_bps: u64,
+    pub new_min_liquidity_bps: u64,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeScheduleChanged {
+    pub previous_fee_schedule: FeeSchedule,
+    pub new_fee_schedule: FeeSchedule,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BasePriceChanged {
+    pub previous_base_price: u64,
+    pub new_base_price: u64,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinOfferAmountChanged {
+    pub previous_min_offer_amount: u64,
+    pub new_min_offer_amount: u64,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingParamsChanged {
+    pub previous_voting_period: i64,
+    pub new_voting_period: i64,
+    pub previous_min_voting_power: u64,
+    pub new_min_voting_power: u64,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakingRatesChanged {
+    pub previous_lock_multipliers_bps: [u64; 5],
+    pub new_lock_multipliers_bps: [u64; 5],
+    pub previous_apy_bps: [u64; 5],
+    pub new_apy_bps: [u64; 5],
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddedToBlocklist {
+    pub wallet: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RemovedFromBlocklist {
+    pub wallet: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralShareChanged {
+    pub previous_referral_share_bps: u64,
+    pub new_referral_share_bps: u64,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionKeyCreated {
+    pub owner: Pubkey,
+    pub session_signer: Pubkey,
+    pub scope: u8,
+    pub expires_at: i64,
+    pub allowance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionKeyRevoked {
+    pub owner: Pubkey,
+    pub session_signer: Pubkey,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttesterAdded {
+    pub attester: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttesterRemoved {
+    pub attester: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerificationIssued {
+    pub wallet: Pubkey,
+    pub attester: Pubkey,
+    pub kind: u8,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerificationRevoked {
+    pub wallet: Pubkey,
+    pub attester: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsernameVerificationSynced {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub verified: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IdentityOracleChanged {
+    pub previous_oracle: Pubkey,
+    pub new_oracle: Pubkey,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IdentityLinked {
+    pub wallet: Pubkey,
+    pub kind: u8,
+    pub handle: String,
+    pub oracle: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IdentityUnlinked {
+    pub wallet: Pubkey,
+    pub kind: u8,
+    pub handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserBlocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub removed_follow: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserUnblocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub timestamp: i64,
+}
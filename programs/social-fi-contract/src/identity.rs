@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+// ==================== Identity Proofs ====================
+//
+// The attestation service signs a statement that a wallet controls an external
+// identity. Clients put that signature in an Ed25519 precompile instruction just
+// before `link_identity`; the runtime verifies it and the program reads the
+// verified key and message back through the instructions sysvar.
+
+/// Native Ed25519 signature verification program
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Domain separator so oracle signatures over other payloads cannot be replayed here
+pub const IDENTITY_MESSAGE_PREFIX: &[u8] = b"social-fi:link-identity:";
+
+/// Size of the per-signature offsets block in Ed25519 instruction data
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Offsets start after the signature count and one padding byte
+const ED25519_OFFSETS_START: usize = 2;
+
+/// Instruction index meaning "this Ed25519 instruction's own data"
+const ED25519_SELF_INDEX: u16 = u16::MAX;
+
+/// Statement the oracle signs: prefix || wallet || kind || expires_at (LE) || handle
+pub fn identity_message(wallet: &Pubkey, kind: u8, expires_at: i64, handle: &str) -> Vec<u8> {
+    let mut message = Vec::with_capacity(IDENTITY_MESSAGE_PREFIX.len() + 32 + 1 + 8 + handle.len());
+    message.extend_from_slice(IDENTITY_MESSAGE_PREFIX);
+    message.extend_from_slice(wallet.as_ref());
+    message.push(kind);
+    message.extend_from_slice(&expires_at.to_le_bytes());
+    message.extend_from_slice(handle.as_bytes());
+    message
+}
+
+/// Public key and message of a single-signature Ed25519 instruction whose
+/// signature, key and message all live in its own data
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, &[u8])> {
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_LEN && data[0] == 1,
+        SocialFiError::InvalidIdentityProof
+    );
+
+    let offsets = &data[ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_LEN];
+    let read = |i: usize| u16::from_le_bytes([offsets[i * 2], offsets[i * 2 + 1]]);
+    let signature_instruction_index = read(1);
+    let public_key_offset = read(2) as usize;
+    let public_key_instruction_index = read(3);
+    let message_offset = read(4) as usize;
+    let message_size = read(5) as usize;
+    let message_instruction_index = read(6);
+
+    // Data pulled from another instruction could point at bytes the oracle never signed
+    require!(
+        signature_instruction_index == ED25519_SELF_INDEX
+            && public_key_instruction_index == ED25519_SELF_INDEX
+            && message_instruction_index == ED25519_SELF_INDEX,
+        SocialFiError::InvalidIdentityProof
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(SocialFiError::InvalidIdentityProof)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(SocialFiError::InvalidIdentityProof)?;
+
+    let public_key = Pubkey::try_from(public_key).map_err(|_| SocialFiError::InvalidIdentityProof)?;
+    Ok((public_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lay out Ed25519 instruction data the way the web3.js helper does
+    fn ed25519_data(public_key: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = (ED25519_OFFSETS_START + ED25519_OFFSETS_LEN) as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn reads_back_key_and_message() {
+        let oracle = Pubkey::new_unique();
+        let message = identity_message(&Pubkey::new_unique(), 0, 1_700_000_000, "alice");
+        let data = ed25519_data(&oracle, &message, ED25519_SELF_INDEX);
+
+        let (public_key, signed) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(public_key, oracle);
+        assert_eq!(signed, &message[..]);
+    }
+
+    #[test]
+    fn message_binds_wallet_kind_and_expiry() {
+        let wallet = Pubkey::new_unique();
+        let message = identity_message(&wallet, 1, 42, "example.com");
+        assert!(message.starts_with(IDENTITY_MESSAGE_PREFIX));
+        assert_ne!(message, identity_message(&Pubkey::new_unique(), 1, 42, "example.com"));
+        assert_ne!(message, identity_message(&wallet, 0, 42, "example.com"));
+        assert_ne!(message, identity_message(&wallet, 1, 43, "example.com"));
+    }
+
+    #[test]
+    fn rejects_data_from_other_instructions() {
+        let data = ed25519_data(&Pubkey::new_unique(), b"statement", 0);
+        assert_eq!(
+            parse_ed25519_instruction(&data).unwrap_err(),
+            error!(SocialFiError::InvalidIdentityProof)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        let mut data = ed25519_data(&Pubkey::new_unique(), b"statement", ED25519_SELF_INDEX);
+        assert!(parse_ed25519_instruction(&data[..10]).is_err());
+
+        // Two signatures
+        data[0] = 2;
+        assert!(parse_ed25519_instruction(&data).is_err());
+
+        // Message running past the end
+        data[0] = 1;
+        data.truncate(data.len() - 1);
+        assert!(parse_ed25519_instruction(&data).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+
+// ==================== Add To Blocklist ====================
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddToBlocklist<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Blocked::LEN,
+        seeds = [BLOCKED_SEED, wallet.as_ref()],
+        bump
+    )]
+    pub blocked: Account<'info, Blocked>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_to_blocklist(ctx: Context<AddToBlocklist>, wallet: Pubkey) -> Result<()> {
+    let blocked = &mut ctx.accounts.blocked;
+    let clock = Clock::get()?;
+
+    blocked.wallet = wallet;
+    blocked.blocked_by = ctx.accounts.admin.key();
+    blocked.blocked_at = clock.unix_timestamp;
+    blocked.bump = ctx.bumps.blocked;
+    blocked.version = ACCOUNT_VERSION;
+
+    emit!(AddedToBlocklist {
+        wallet,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Remove From Blocklist ====================
+
+#[derive(Accounts)]
+pub struct RemoveFromBlocklist<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [BLOCKED_SEED, blocked.wallet.as_ref()],
+        bump = blocked.bump,
+        close = admin
+    )]
+    pub blocked: Account<'info, Blocked>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn remove_from_blocklist(ctx: Context<RemoveFromBlocklist>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(RemovedFromBlocklist {
+        wallet: ctx.accounts.blocked.wallet,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
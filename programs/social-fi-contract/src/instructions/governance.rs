@@ -23,10 +23,23 @@ pub struct StakeTokens<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_GOVERNANCE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -38,14 +51,7 @@ pub fn stake_tokens(
     require!(amount > 0, SocialFiError::InvalidAmount);
     
     // Validate lock period
-    require!(
-        lock_period == LOCK_0_DAYS ||
-        lock_period == LOCK_30_DAYS ||
-        lock_period == LOCK_90_DAYS ||
-        lock_period == LOCK_180_DAYS ||
-        lock_period == LOCK_365_DAYS,
-        SocialFiError::InvalidLockPeriod
-    );
+    ProtocolParams::lock_tier(lock_period)?;
 
     let stake_position = &mut ctx.accounts.stake_position;
     let clock = Clock::get()?;
@@ -61,7 +67,8 @@ pub fn stake_tokens(
         .ok_or(SocialFiError::ArithmeticOverflow)?;
     
     // Calculate voting power
-    let voting_power = StakePosition::calculate_voting_power(amount, lock_period)?;
+    let voting_power = StakePosition::calculate_voting_power(amount, lock_period, &ctx.accounts.protocol_params)?;
+    let apy_bps = ctx.accounts.protocol_params.apy_bps[ProtocolParams::lock_tier(lock_period)?];
 
     // Initialize stake position
     stake_position.staker = ctx.accounts.staker.key();
@@ -72,6 +79,14 @@ pub fn stake_tokens(
     stake_position.rewards = 0;
     stake_position.voting_power = voting_power;
     stake_position.bump = ctx.bumps.stake_position;
+    stake_position.version = ACCOUNT_VERSION;
+    stake_position.apy_bps = Some(apy_bps);
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_staked = platform_stats
+        .total_staked
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     // NOTE: In production, this would transfer tokens to a vault
     // For now, we're just tracking the stake
@@ -106,9 +121,22 @@ pub struct UnstakeTokens<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_GOVERNANCE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
 }
 
 pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
@@ -121,12 +149,18 @@ pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
     );
 
     // Calculate rewards
-    let rewards = stake_position.calculate_rewards(clock.unix_timestamp)?;
+    let rewards = stake_position.calculate_rewards(clock.unix_timestamp, &ctx.accounts.protocol_params)?;
     let _total_return = stake_position
         .amount
         .checked_add(rewards)
         .ok_or(SocialFiError::ArithmeticOverflow)?;
 
+    // Saturating: positions opened before the stats account existed were never counted
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_staked = platform_stats
+        .total_staked
+        .saturating_sub(stake_position.amount);
+
     // NOTE: In production, this would transfer tokens from vault back to user
     
     emit!(TokensUnstaked {
@@ -156,7 +190,7 @@ pub struct CreateProposal<'info> {
     #[account(
         seeds = [STAKE_POSITION_SEED, proposer.key().as_ref()],
         bump = stake_position.bump,
-        constraint = stake_position.voting_power >= MIN_VOTING_POWER @ SocialFiError::InsufficientVotingPower
+        constraint = stake_position.voting_power >= protocol_params.min_voting_power @ SocialFiError::InsufficientVotingPower
     )]
     pub stake_position: Account<'info, StakePosition>,
     
@@ -166,10 +200,16 @@ pub struct CreateProposal<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_GOVERNANCE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -198,7 +238,7 @@ pub fn create_proposal(
     
     let voting_ends_at = clock
         .unix_timestamp
-        .checked_add(VOTING_PERIOD)
+        .checked_add(ctx.accounts.protocol_params.voting_period)
         .ok_or(SocialFiError::ArithmeticOverflow)?;
     
     // Calculate quorum (10% of total staked)
@@ -225,6 +265,7 @@ pub fn create_proposal(
     proposal.quorum_required = quorum_required;
     proposal.executed_at = None;
     proposal.bump = ctx.bumps.proposal;
+    proposal.version = ACCOUNT_VERSION;
 
     emit!(ProposalCreated {
         proposal: ctx.accounts.proposal.key(),
@@ -270,7 +311,7 @@ pub struct CastVote<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_GOVERNANCE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
@@ -297,6 +338,7 @@ pub fn cast_vote(ctx: Context<CastVote>, vote_type: u8) -> Result<()> {
     vote.voting_power = voting_power;
     vote.voted_at = clock.unix_timestamp;
     vote.bump = ctx.bumps.vote;
+    vote.version = ACCOUNT_VERSION;
 
     // Update proposal vote counts
     let proposal = &mut ctx.accounts.proposal;
@@ -345,6 +387,13 @@ pub struct ExecuteProposal<'info> {
     pub proposal: Account<'info, Proposal>,
     
     pub executor: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_GOVERNANCE) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
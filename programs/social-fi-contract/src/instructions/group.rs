@@ -31,6 +31,20 @@ pub struct CreateGroup<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_GROUPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -72,6 +86,13 @@ pub fn create_group(
     group.post_count = 0;
     group.created_at = clock.unix_timestamp;
     group.bump = ctx.bumps.group;
+    group.version = ACCOUNT_VERSION;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_groups = platform_stats
+        .total_groups
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     // Initialize creator as owner
     group_member.group = ctx.accounts.group.key();
@@ -80,6 +101,7 @@ pub fn create_group(
     group_member.joined_at = clock.unix_timestamp;
     group_member.banned = false;
     group_member.bump = ctx.bumps.group_member;
+    group_member.version = ACCOUNT_VERSION;
 
     emit!(GroupCreated {
         group: ctx.accounts.group.key(),
@@ -93,6 +115,7 @@ pub fn create_group(
         group: ctx.accounts.group.key(),
         member: ctx.accounts.creator.key(),
         role: 0,
+        fee: 0,
         timestamp: clock.unix_timestamp,
     });
 
@@ -126,6 +149,36 @@ pub struct JoinGroup<'info> {
     #[account(mut)]
     pub group_creator: AccountInfo<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_GROUPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the member; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, member.key().as_ref()],
+        bump,
+        constraint = member_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub member_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the group creator; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, group.creator.as_ref()],
+        bump,
+        constraint = group_creator_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub group_creator_blocked: AccountInfo<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -136,6 +189,10 @@ pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
     // ===== CHECKS =====
     let entry_requirement = group.entry_requirement;
     let entry_price = group.entry_price;
+    let fee = match (entry_requirement, entry_price) {
+        (1, Some(price)) => PlatformConfig::calculate_fee(price, ctx.accounts.platform_config.fee_schedule.group_entry_bps)?,
+        _ => 0,
+    };
 
     // ===== EFFECTS (Update state BEFORE external calls) =====
     // Initialize member
@@ -146,6 +203,7 @@ pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
     group_member.joined_at = clock.unix_timestamp;
     group_member.banned = false;
     group_member.bump = ctx.bumps.group_member;
+    group_member.version = ACCOUNT_VERSION;
 
     // Update member count
     let group = &mut ctx.accounts.group;
@@ -160,6 +218,9 @@ pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
         1 => {
             // Pay SOL
             if let Some(price) = entry_price {
+                let creator_amount = price
+                    .checked_sub(fee)
+                    .ok_or(SocialFiError::ArithmeticUnderflow)?;
                 let cpi_context = CpiContext::new(
                     ctx.accounts.system_program.to_account_info(),
                     Transfer {
@@ -167,7 +228,18 @@ pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
                         to: ctx.accounts.group_creator.to_account_info(),
                     },
                 );
-                transfer(cpi_context, price)?;
+                transfer(cpi_context, creator_amount)?;
+
+                if fee > 0 {
+                    let cpi_context = CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.member.to_account_info(),
+                            to: ctx.accounts.fee_collector.to_account_info(),
+                        },
+                    );
+                    transfer(cpi_context, fee)?;
+                }
             }
         }
         2 | 3 => {
@@ -182,6 +254,7 @@ pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
         group: ctx.accounts.group.key(),
         member: ctx.accounts.member.key(),
         role: 3,
+        fee,
         timestamp: clock.unix_timestamp,
     });
 
@@ -213,10 +286,17 @@ pub struct UpdateMemberRole<'info> {
     pub target_member: Account<'info, GroupMember>,
     
     pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_GROUPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn update_member_role(ctx: Context<UpdateMemberRole>, new_role: u8) -> Result<()> {
-    require!(new_role >= 1 && new_role <= 3, SocialFiError::InsufficientPermissions);
+    require!((1..=3).contains(&new_role), SocialFiError::InsufficientPermissions);
     require!(
         ctx.accounts.admin.key() != ctx.accounts.target_member.wallet,
         SocialFiError::CannotActOnSelf
@@ -267,6 +347,13 @@ pub struct KickMember<'info> {
     
     #[account(mut)]
     pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_GROUPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn kick_member(ctx: Context<KickMember>) -> Result<()> {
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::identity::{identity_message, parse_ed25519_instruction, ED25519_PROGRAM_ID};
+
+// ==================== Link Identity ====================
+
+#[derive(Accounts)]
+#[instruction(kind: u8)]
+pub struct LinkIdentity<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = LinkedIdentity::LEN,
+        seeds = [LINKED_IDENTITY_SEED, owner.key().as_ref(), &[kind]],
+        bump
+    )]
+    pub linked_identity: Account<'info, LinkedIdentity>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Instructions sysvar
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Link an external identity attested by the identity oracle. The transaction must carry,
+/// immediately before this instruction, an Ed25519 instruction in which the oracle signs
+/// `identity_message(owner, kind, expires_at, handle)`. Relinking a kind replaces the handle.
+pub fn link_identity(ctx: Context<LinkIdentity>, kind: u8, handle: String, expires_at: i64) -> Result<()> {
+    // ===== CHECKS =====
+    require!(
+        matches!(kind, IDENTITY_KIND_SOCIAL | IDENTITY_KIND_DOMAIN),
+        SocialFiError::InvalidIdentity
+    );
+    require!(
+        !handle.is_empty() && handle.len() <= MAX_IDENTITY_HANDLE_LENGTH,
+        SocialFiError::InvalidIdentity
+    );
+
+    let oracle = ctx.accounts.platform_config.identity_oracle;
+    require!(oracle != Pubkey::default(), SocialFiError::IdentityOracleNotSet);
+
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp < expires_at, SocialFiError::IdentityProofExpired);
+
+    let owner = ctx.accounts.owner.key();
+    verify_oracle_signature(
+        &ctx.accounts.instructions_sysvar,
+        &oracle,
+        &identity_message(&owner, kind, expires_at, &handle),
+    )?;
+
+    // ===== EFFECTS =====
+    let linked_identity = &mut ctx.accounts.linked_identity;
+    linked_identity.wallet = owner;
+    linked_identity.profile = ctx.accounts.user_profile.key();
+    linked_identity.kind = kind;
+    linked_identity.handle = handle.clone();
+    linked_identity.oracle = oracle;
+    linked_identity.linked_at = clock.unix_timestamp;
+    linked_identity.bump = ctx.bumps.linked_identity;
+    linked_identity.version = ACCOUNT_VERSION;
+
+    emit!(IdentityLinked {
+        wallet: owner,
+        kind,
+        handle,
+        oracle,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Check that the instruction before the current one is an Ed25519 verification of
+/// `expected_message` by `oracle`. The runtime has already verified the signature itself.
+fn verify_oracle_signature(instructions_sysvar: &AccountInfo, oracle: &Pubkey, expected_message: &[u8]) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, SocialFiError::InvalidIdentityProof);
+
+    let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require!(
+        ed25519_ix.program_id == ED25519_PROGRAM_ID && ed25519_ix.accounts.is_empty(),
+        SocialFiError::InvalidIdentityProof
+    );
+
+    let (signer, message) = parse_ed25519_instruction(&ed25519_ix.data)?;
+    require!(signer == *oracle, SocialFiError::IdentityOracleMismatch);
+    require!(message == expected_message, SocialFiError::IdentityMessageMismatch);
+    Ok(())
+}
+
+// ==================== Unlink Identity ====================
+
+#[derive(Accounts)]
+pub struct UnlinkIdentity<'info> {
+    #[account(
+        mut,
+        seeds = [LINKED_IDENTITY_SEED, owner.key().as_ref(), &[linked_identity.kind]],
+        bump = linked_identity.bump,
+        constraint = linked_identity.wallet == owner.key() @ SocialFiError::Unauthorized,
+        close = owner
+    )]
+    pub linked_identity: Account<'info, LinkedIdentity>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn unlink_identity(ctx: Context<UnlinkIdentity>) -> Result<()> {
+    let clock = Clock::get()?;
+    let linked_identity = &ctx.accounts.linked_identity;
+
+    emit!(IdentityUnlinked {
+        wallet: linked_identity.wallet,
+        kind: linked_identity.kind,
+        handle: linked_identity.handle.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -7,6 +7,9 @@ use crate::state::*;
 use crate::errors::*;
 use crate::events::*;
 use crate::constants::*;
+use crate::username::normalize_handle;
+use crate::instructions::referral::{referral_fee, pay_referral_reward};
+use crate::instructions::user::load_program_account;
 
 // ==================== Mint Username ====================
 
@@ -17,7 +20,7 @@ pub struct MintUsername<'info> {
         init,
         payer = owner,
         space = UsernameNFT::LEN,
-        seeds = [USERNAME_NFT_SEED, username.as_bytes()],
+        seeds = [USERNAME_NFT_SEED, normalize_handle(&username).unwrap_or_default().as_bytes()],
         bump
     )]
     pub username_nft: Account<'info, UsernameNFT>,
@@ -57,7 +60,7 @@ pub struct MintUsername<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
@@ -69,14 +72,7 @@ pub struct MintUsername<'info> {
 }
 
 pub fn mint_username(ctx: Context<MintUsername>, username: String, metadata_uri: String) -> Result<()> {
-    require!(
-        username.len() <= MAX_USERNAME_LENGTH,
-        SocialFiError::UsernameTooLong
-    );
-    require!(
-        username.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.'),
-        SocialFiError::InvalidUsername
-    );
+    let username = normalize_handle(&username)?;
     require!(
         metadata_uri.len() <= 200,
         SocialFiError::MetadataUriTooLong
@@ -94,6 +90,7 @@ pub fn mint_username(ctx: Context<MintUsername>, username: String, metadata_uri:
     username_nft.verified = false;
     username_nft.minted_at = clock.unix_timestamp;
     username_nft.bump = bump;
+    username_nft.version = ACCOUNT_VERSION;
 
     // Mint 1 NFT token to owner's account
     let seeds = &[
@@ -213,13 +210,21 @@ pub struct ListUsername<'info> {
     )]
     pub listing: Account<'info, Listing>,
     
+    /// Seller's profile, tracking open listings
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, seller.key().as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+    
     #[account(mut)]
     pub seller: Signer<'info>,
     
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
@@ -249,6 +254,13 @@ pub fn list_username(ctx: Context<ListUsername>, price: u64) -> Result<()> {
     listing.listed_at = clock.unix_timestamp;
     listing.expires_at = None;
     listing.bump = ctx.bumps.listing;
+    listing.version = ACCOUNT_VERSION;
+
+    let seller_profile = &mut ctx.accounts.seller_profile;
+    seller_profile.open_listings = seller_profile
+        .open_listings
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     emit!(UsernameListed {
         seller: ctx.accounts.seller.key(),
@@ -261,6 +273,54 @@ pub fn list_username(ctx: Context<ListUsername>, price: u64) -> Result<()> {
     Ok(())
 }
 
+/// Drop the seller's primary username binding to a sold NFT, refunding the resolver
+/// rent to the seller. Returns whether anything was unbound.
+fn unbind_sold_username(
+    seller_profile: &AccountInfo,
+    username_resolver: &AccountInfo,
+    seller: &AccountInfo,
+    mint: Pubkey,
+) -> Result<bool> {
+    let mut unbound = false;
+    if !seller_profile.data_is_empty() && seller_profile.owner == &crate::ID {
+        let mut data = seller_profile.try_borrow_mut_data()?;
+        let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+        if profile.primary_username_mint == mint {
+            profile.primary_username_mint = Pubkey::default();
+            profile.try_serialize(&mut &mut data[..])?;
+            unbound = true;
+        }
+    }
+
+    let bound_to_seller = load_program_account::<UsernameResolver>(username_resolver)?
+        .is_some_and(|resolver| resolver.profile == seller_profile.key());
+    if bound_to_seller {
+        let lamports = username_resolver.lamports();
+        **seller.try_borrow_mut_lamports()? = seller
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+        **username_resolver.try_borrow_mut_lamports()? = 0;
+        username_resolver.assign(&System::id());
+        username_resolver.resize(0)?;
+        unbound = true;
+    }
+
+    Ok(unbound)
+}
+
+/// Decrement the seller's open listing count. Sellers without a profile are skipped.
+fn release_listing_slot(seller_profile: &AccountInfo) -> Result<()> {
+    if seller_profile.data_is_empty() || seller_profile.owner != &crate::ID {
+        return Ok(());
+    }
+    let mut data = seller_profile.try_borrow_mut_data()?;
+    let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+    // Listings created before profiles counted them were never added
+    profile.open_listings = profile.open_listings.saturating_sub(1);
+    profile.try_serialize(&mut &mut data[..])
+}
+
 // ==================== Buy Listing ====================
 
 #[derive(Accounts)]
@@ -308,13 +368,74 @@ pub struct BuyListing<'info> {
     #[account(mut)]
     pub seller: AccountInfo<'info>,
     
+    /// CHECK: Seller's profile; its open listing count is decremented when it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, listing.seller.as_ref()],
+        bump
+    )]
+    pub seller_profile: AccountInfo<'info>,
+    
+    /// CHECK: Primary username binding of the NFT; closed when it points at the seller's profile
+    #[account(
+        mut,
+        seeds = [USERNAME_RESOLVER_SEED, username_nft.username.as_bytes()],
+        bump
+    )]
+    pub username_resolver: AccountInfo<'info>,
+    
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, buyer.key().as_ref()],
+        bump,
+        constraint = buyer_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub buyer_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the seller; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, listing.seller.as_ref()],
+        bump,
+        constraint = seller_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub seller_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Buyer's profile, if any; when it names a referrer, `referral_rewards` must be theirs
+    #[account(
+        seeds = [USER_PROFILE_SEED, buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_profile: AccountInfo<'info>,
+    
+    /// Rewards PDA of the referrer named in `buyer_profile.referred_by`
+    #[account(
+        mut,
+        seeds = [REFERRAL_REWARDS_SEED, referral_rewards.referrer.as_ref()],
+        bump = referral_rewards.bump
+    )]
+    pub referral_rewards: Option<Account<'info, ReferralRewards>>,
+    
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -326,6 +447,10 @@ pub fn buy_listing(ctx: Context<BuyListing>) -> Result<()> {
     let price = listing.price;
     let seller_key = listing.seller;
     let username = listing.username.clone();
+    let fee = PlatformConfig::calculate_fee(price, ctx.accounts.platform_config.fee_schedule.marketplace_bps)?;
+    let seller_amount = price
+        .checked_sub(fee)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
     
     let clock = Clock::get()?;
     
@@ -341,6 +466,20 @@ pub fn buy_listing(ctx: Context<BuyListing>) -> Result<()> {
     // Transfer NFT ownership in PDA
     let username_nft = &mut ctx.accounts.username_nft;
     username_nft.owner = ctx.accounts.buyer.key();
+    let mint = username_nft.mint;
+    release_listing_slot(&ctx.accounts.seller_profile)?;
+    let unbound = unbind_sold_username(
+        &ctx.accounts.seller_profile,
+        &ctx.accounts.username_resolver,
+        &ctx.accounts.seller,
+        mint,
+    )?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_marketplace_volume = platform_stats
+        .total_marketplace_volume
+        .checked_add(price)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     // ===== INTERACTIONS (External calls LAST) =====
     // Transfer SPL token from seller to buyer
@@ -364,16 +503,61 @@ pub fn buy_listing(ctx: Context<BuyListing>) -> Result<()> {
             to: ctx.accounts.seller.to_account_info(),
         },
     );
-    transfer(cpi_context, price)?;
+    transfer(cpi_context, seller_amount)?;
+
+    let referral_amount = referral_fee(
+        &ctx.accounts.platform_config,
+        fee,
+        &ctx.accounts.buyer_profile,
+        ctx.accounts.referral_rewards.as_ref(),
+    )?;
+    let protocol_fee = fee
+        .checked_sub(referral_amount)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+    if protocol_fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.fee_collector.to_account_info(),
+            },
+        );
+        transfer(cpi_context, protocol_fee)?;
+    }
+
+    if referral_amount > 0 {
+        if let Some(referral_rewards) = ctx.accounts.referral_rewards.as_mut() {
+            pay_referral_reward(
+                referral_rewards,
+                ctx.accounts.buyer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                &[],
+                ctx.accounts.buyer.key(),
+                referral_amount,
+            )?;
+        }
+    }
 
     emit!(UsernameSold {
         seller: seller_key,
         buyer: ctx.accounts.buyer.key(),
         username,
         price,
+        fee,
         timestamp: clock.unix_timestamp,
     });
 
+    if unbound {
+        emit!(PrimaryUsernameCleared {
+            user: seller_key,
+            profile: ctx.accounts.seller_profile.key(),
+            mint,
+            signer: ctx.accounts.buyer.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     Ok(())
 }
 
@@ -408,16 +592,41 @@ pub struct MakeOffer<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, buyer.key().as_ref()],
+        bump,
+        constraint = buyer_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub buyer_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the seller; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, listing.seller.as_ref()],
+        bump,
+        constraint = seller_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub seller_blocked: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn make_offer(ctx: Context<MakeOffer>, amount: u64) -> Result<()> {
     require!(amount > 0, SocialFiError::InvalidOfferAmount);
-    require!(amount >= MIN_OFFER_AMOUNT, SocialFiError::InvalidOfferAmount);
+    require!(
+        amount >= ctx.accounts.protocol_params.min_offer_amount,
+        SocialFiError::InvalidOfferAmount
+    );
 
     let offer = &mut ctx.accounts.offer;
     let clock = Clock::get()?;
@@ -434,6 +643,7 @@ pub fn make_offer(ctx: Context<MakeOffer>, amount: u64) -> Result<()> {
     offer.created_at = clock.unix_timestamp;
     offer.expires_at = expires_at;
     offer.bump = ctx.bumps.offer;
+    offer.version = ACCOUNT_VERSION;
 
     emit!(OfferMade {
         buyer: ctx.accounts.buyer.key(),
@@ -499,17 +709,63 @@ pub struct AcceptOffer<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
     
+    /// CHECK: Seller's profile; its open listing count is decremented when it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, seller.key().as_ref()],
+        bump
+    )]
+    pub seller_profile: AccountInfo<'info>,
+    
     /// CHECK: Buyer verified through offer.buyer - no signature needed (funds escrowed)
     #[account(mut)]
     pub buyer: AccountInfo<'info>,
     
+    /// CHECK: Primary username binding of the NFT; closed when it points at the seller's profile
+    #[account(
+        mut,
+        seeds = [USERNAME_RESOLVER_SEED, username_nft.username.as_bytes()],
+        bump
+    )]
+    pub username_resolver: AccountInfo<'info>,
+    
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, buyer.key().as_ref()],
+        bump,
+        constraint = buyer_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub buyer_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the seller; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, seller.key().as_ref()],
+        bump,
+        constraint = seller_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub seller_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -529,6 +785,14 @@ pub fn accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
     // Transfer NFT ownership in PDA
     let username_nft = &mut ctx.accounts.username_nft;
     username_nft.owner = ctx.accounts.buyer.key();
+    let mint = username_nft.mint;
+    release_listing_slot(&ctx.accounts.seller_profile)?;
+    let unbound = unbind_sold_username(
+        &ctx.accounts.seller_profile,
+        &ctx.accounts.username_resolver,
+        &ctx.accounts.seller.to_account_info(),
+        mint,
+    )?;
 
     // ===== INTERACTIONS (External calls LAST) =====
     // Transfer SPL token from seller to buyer
@@ -547,19 +811,41 @@ pub fn accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
     // ===== ESCROW: Withdraw SOL from offer PDA to seller =====
     let offer_lamports = ctx.accounts.offer.to_account_info().lamports();
     let rent_exempt = Rent::get()?.minimum_balance(ctx.accounts.offer.to_account_info().data_len());
-    let payment_amount = offer_lamports.checked_sub(rent_exempt).unwrap_or(0);
+    let payment_amount = offer_lamports.saturating_sub(rent_exempt);
+    let fee = PlatformConfig::calculate_fee(payment_amount, ctx.accounts.platform_config.fee_schedule.marketplace_bps)?;
+    let seller_amount = payment_amount
+        .checked_sub(fee)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
     
     **ctx.accounts.offer.to_account_info().try_borrow_mut_lamports()? -= payment_amount;
-    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += payment_amount;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += seller_amount;
+    **ctx.accounts.fee_collector.to_account_info().try_borrow_mut_lamports()? += fee;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_marketplace_volume = platform_stats
+        .total_marketplace_volume
+        .checked_add(payment_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     emit!(OfferAccepted {
         seller: ctx.accounts.seller.key(),
         buyer: ctx.accounts.buyer.key(),
         listing: ctx.accounts.listing.key(),
         amount: offer.amount,
+        fee,
         timestamp: clock.unix_timestamp,
     });
 
+    if unbound {
+        emit!(PrimaryUsernameCleared {
+            user: ctx.accounts.seller.key(),
+            profile: ctx.accounts.seller_profile.key(),
+            mint,
+            signer: ctx.accounts.seller.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     Ok(())
 }
 
@@ -579,6 +865,13 @@ pub struct CancelOffer<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -594,7 +887,7 @@ pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
     // Must withdraw lamports directly, not via transfer()
     let offer_lamports = ctx.accounts.offer.to_account_info().lamports();
     let rent_exempt = Rent::get()?.minimum_balance(ctx.accounts.offer.to_account_info().data_len());
-    let refund_amount = offer_lamports.checked_sub(rent_exempt).unwrap_or(0);
+    let refund_amount = offer_lamports.saturating_sub(rent_exempt);
     
     **ctx.accounts.offer.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
     **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += refund_amount;
@@ -633,10 +926,26 @@ pub struct CancelListing<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    /// CHECK: Seller's profile; its open listing count is decremented when it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, seller.key().as_ref()],
+        bump
+    )]
+    pub seller_profile: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    release_listing_slot(&ctx.accounts.seller_profile)?;
     let clock = Clock::get()?;
 
     emit!(ListingCancelled {
@@ -648,3 +957,238 @@ pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
 
     Ok(())
 }
+
+// ==================== Primary Username ====================
+
+#[derive(Accounts)]
+pub struct SetPrimaryUsername<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        mut,
+        seeds = [USERNAME_NFT_SEED, username_nft.username.as_bytes()],
+        bump = username_nft.bump
+    )]
+    pub username_nft: Account<'info, UsernameNFT>,
+    
+    /// Owner's token account - must hold the NFT to bind it
+    #[account(
+        constraint = token_account.mint == username_nft.mint @ SocialFiError::NotUsernameOwner,
+        constraint = token_account.owner == owner.key() @ SocialFiError::NotUsernameOwner,
+        constraint = token_account.amount == 1 @ SocialFiError::NotUsernameOwner,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = UsernameResolver::LEN,
+        seeds = [USERNAME_RESOLVER_SEED, username_nft.username.as_bytes()],
+        bump
+    )]
+    pub username_resolver: Account<'info, UsernameResolver>,
+    
+    /// Resolver of the username the profile currently names; required when switching
+    /// to another NFT, and closed if it still points at this profile
+    #[account(
+        mut,
+        constraint = previous_resolver.mint == user_profile.primary_username_mint
+            && previous_resolver.mint != username_nft.mint @ SocialFiError::InvalidPreviousResolver
+    )]
+    pub previous_resolver: Option<Account<'info, UsernameResolver>>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_MARKETPLACE) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_primary_username(ctx: Context<SetPrimaryUsername>) -> Result<()> {
+    let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
+    let profile_key = ctx.accounts.user_profile.key();
+
+    // ===== CHECKS =====
+    let previous_mint = ctx.accounts.user_profile.primary_username_mint;
+    if previous_mint != Pubkey::default() && previous_mint != ctx.accounts.username_nft.mint {
+        require!(
+            ctx.accounts.previous_resolver.is_some(),
+            SocialFiError::InvalidPreviousResolver
+        );
+    }
+
+    // ===== EFFECTS =====
+    // A newer holder of the previous NFT may already have re-bound its resolver
+    if let Some(previous_resolver) = &ctx.accounts.previous_resolver {
+        if previous_resolver.profile == profile_key {
+            previous_resolver.close(ctx.accounts.owner.to_account_info())?;
+        }
+    }
+
+    // The token account is authoritative; catch up with transfers made outside the marketplace
+    let username_nft = &mut ctx.accounts.username_nft;
+    username_nft.owner = owner;
+    let mint = username_nft.mint;
+    let username = username_nft.username.clone();
+
+    // Any earlier binding by a previous holder is overwritten
+    let username_resolver = &mut ctx.accounts.username_resolver;
+    username_resolver.username = username.clone();
+    username_resolver.mint = mint;
+    username_resolver.profile = profile_key;
+    username_resolver.owner = owner;
+    username_resolver.bound_at = clock.unix_timestamp;
+    username_resolver.bump = ctx.bumps.username_resolver;
+    username_resolver.version = ACCOUNT_VERSION;
+
+    ctx.accounts.user_profile.primary_username_mint = mint;
+
+    emit!(PrimaryUsernameSet {
+        user: owner,
+        profile: profile_key,
+        username,
+        mint,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClearPrimaryUsername<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, user_profile.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        seeds = [USERNAME_NFT_SEED, username_nft.username.as_bytes()],
+        bump = username_nft.bump,
+        constraint = username_nft.mint == user_profile.primary_username_mint @ SocialFiError::NotUsernameOwner
+    )]
+    pub username_nft: Account<'info, UsernameNFT>,
+    
+    #[account(
+        mut,
+        seeds = [USERNAME_RESOLVER_SEED, username_nft.username.as_bytes()],
+        bump = username_resolver.bump
+    )]
+    pub username_resolver: Account<'info, UsernameResolver>,
+    
+    /// Current holder's token account; required unless the profile owner clears their own binding
+    #[account(
+        constraint = holder_token_account.mint == username_nft.mint @ SocialFiError::NotUsernameOwner,
+        constraint = holder_token_account.amount == 1 @ SocialFiError::NotUsernameOwner,
+    )]
+    pub holder_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Profile owner receiving the resolver rent; verified against the profile
+    #[account(
+        mut,
+        address = user_profile.owner @ SocialFiError::Unauthorized
+    )]
+    pub profile_owner: AccountInfo<'info>,
+    
+    pub signer: Signer<'info>,
+}
+
+/// Unbind a profile's primary username. The profile owner may always do so; anyone
+/// else may once the holder's token account shows the NFT has left the owner's wallet.
+pub fn clear_primary_username(ctx: Context<ClearPrimaryUsername>) -> Result<()> {
+    let profile_owner = ctx.accounts.user_profile.owner;
+    let signer = ctx.accounts.signer.key();
+
+    // ===== CHECKS =====
+    if signer != profile_owner {
+        let holder = ctx.accounts.holder_token_account
+            .as_ref()
+            .ok_or(SocialFiError::NotUsernameOwner)?;
+        require!(
+            !ctx.accounts.username_resolver.is_bound(&ctx.accounts.user_profile, &holder.owner),
+            SocialFiError::PrimaryUsernameStillBound
+        );
+    }
+
+    // ===== EFFECTS =====
+    let mint = ctx.accounts.user_profile.primary_username_mint;
+    ctx.accounts.user_profile.primary_username_mint = Pubkey::default();
+
+    // A newer holder may already have re-bound the resolver to their profile
+    if ctx.accounts.username_resolver.profile == ctx.accounts.user_profile.key() {
+        ctx.accounts.username_resolver.close(ctx.accounts.profile_owner.to_account_info())?;
+    }
+
+    let clock = Clock::get()?;
+    emit!(PrimaryUsernameCleared {
+        user: profile_owner,
+        profile: ctx.accounts.user_profile.key(),
+        mint,
+        signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Username Verification ====================
+
+#[derive(Accounts)]
+pub struct SyncUsernameVerification<'info> {
+    #[account(
+        mut,
+        seeds = [USERNAME_NFT_SEED, username_nft.username.as_bytes()],
+        bump = username_nft.bump
+    )]
+    pub username_nft: Account<'info, UsernameNFT>,
+
+    /// Token account currently holding the NFT
+    #[account(
+        constraint = holder_token_account.mint == username_nft.mint @ SocialFiError::NotUsernameOwner,
+        constraint = holder_token_account.amount == 1 @ SocialFiError::NotUsernameOwner,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Verification PDA of the holder; may not exist
+    #[account(
+        seeds = [VERIFICATION_SEED, holder_token_account.owner.as_ref()],
+        bump
+    )]
+    pub verification: AccountInfo<'info>,
+}
+
+/// Permissionless: copy the holder's verification status onto the username NFT.
+/// Crank again after a verification is issued, revoked or expires.
+pub fn sync_username_verification(ctx: Context<SyncUsernameVerification>) -> Result<()> {
+    let clock = Clock::get()?;
+    let verified = load_program_account::<Verification>(&ctx.accounts.verification)?
+        .is_some_and(|verification| verification.is_active(clock.unix_timestamp));
+
+    // The token account is authoritative; catch up with transfers made outside the marketplace
+    let username_nft = &mut ctx.accounts.username_nft;
+    username_nft.owner = ctx.accounts.holder_token_account.owner;
+    username_nft.verified = verified;
+
+    emit!(UsernameVerificationSynced {
+        mint: username_nft.mint,
+        owner: username_nft.owner,
+        verified,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
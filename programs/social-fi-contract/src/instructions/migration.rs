@@ -0,0 +1,393 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+
+// ==================== Migrate Account ====================
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Any program-owned account; its type is resolved from the discriminator
+    #[account(mut, owner = crate::ID)]
+    pub account: AccountInfo<'info>,
+
+    /// Pays the extra rent for the grown account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless upgrade of any account (other than `PlatformConfig`) to the
+/// current layout. Only padding and the version byte are added, so no field an
+/// owner cares about can change.
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let discriminator = {
+        let data = account.try_borrow_data()?;
+        require!(data.len() >= 8, SocialFiError::UnsupportedAccountType);
+        data[..8].to_vec()
+    };
+
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let accounts = (&account, &payer, &system_program);
+
+    let from_version = if discriminator == UserProfile::DISCRIMINATOR {
+        upgrade_account::<UserProfile>(accounts)?
+    } else if discriminator == CreatorPool::DISCRIMINATOR {
+        upgrade_account::<CreatorPool>(accounts)?
+    } else if discriminator == ShareHolding::DISCRIMINATOR {
+        upgrade_account::<ShareHolding>(accounts)?
+    } else if discriminator == SubscriptionTier::DISCRIMINATOR {
+        upgrade_account::<SubscriptionTier>(accounts)?
+    } else if discriminator == Subscription::DISCRIMINATOR {
+        upgrade_account::<Subscription>(accounts)?
+    } else if discriminator == Group::DISCRIMINATOR {
+        upgrade_account::<Group>(accounts)?
+    } else if discriminator == GroupMember::DISCRIMINATOR {
+        upgrade_account::<GroupMember>(accounts)?
+    } else if discriminator == StakePosition::DISCRIMINATOR {
+        upgrade_account::<StakePosition>(accounts)?
+    } else if discriminator == Proposal::DISCRIMINATOR {
+        upgrade_account::<Proposal>(accounts)?
+    } else if discriminator == Vote::DISCRIMINATOR {
+        upgrade_account::<Vote>(accounts)?
+    } else if discriminator == UsernameNFT::DISCRIMINATOR {
+        upgrade_account::<UsernameNFT>(accounts)?
+    } else if discriminator == Listing::DISCRIMINATOR {
+        upgrade_account::<Listing>(accounts)?
+    } else if discriminator == Offer::DISCRIMINATOR {
+        upgrade_account::<Offer>(accounts)?
+    } else if discriminator == Post::DISCRIMINATOR {
+        upgrade_account::<Post>(accounts)?
+    } else if discriminator == Follow::DISCRIMINATOR {
+        upgrade_account::<Follow>(accounts)?
+    } else if discriminator == Like::DISCRIMINATOR {
+        upgrade_account::<Like>(accounts)?
+    } else if discriminator == Repost::DISCRIMINATOR {
+        upgrade_account::<Repost>(accounts)?
+    } else if discriminator == Comment::DISCRIMINATOR {
+        upgrade_account::<Comment>(accounts)?
+    } else {
+        return Err(error!(SocialFiError::UnsupportedAccountType));
+    };
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: account.key(),
+        from_version,
+        to_version: ACCOUNT_VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Migrate Platform Config ====================
+
+/// Platform config layout before versioning (admin, fee collector, single pause flag)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PlatformConfigV0 {
+    pub admin: Pubkey,
+    pub fee_collector: Pubkey,
+    pub paused: bool,
+    pub min_liquidity_bps: u64,
+    pub bump: u8,
+}
+
+impl PlatformConfigV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1;
+
+    pub fn upgrade(self) -> PlatformConfig {
+        PlatformConfig {
+            admin: self.admin,
+            fee_collector: self.fee_collector,
+            paused_modules: if self.paused { PAUSE_ALL } else { 0 },
+            min_liquidity_bps: self.min_liquidity_bps,
+            pending_admin: None,
+            admin_transfer_eta: 0,
+            fee_schedule: FeeSchedule::default_schedule(),
+            bump: self.bump,
+            version: ACCOUNT_VERSION,
+            guardian: Pubkey::default(),
+            referral_share_bps: DEFAULT_REFERRAL_SHARE_BPS,
+            identity_oracle: Pubkey::default(),
+            reserved: [0; 56],
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigratePlatformConfig<'info> {
+    /// CHECK: Legacy platform config; layout and admin are verified in the handler
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        owner = crate::ID
+    )]
+    pub platform_config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Admin-only, since the upgrade fills in the fee schedule and pause flags
+pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+    let account = ctx.accounts.platform_config.to_account_info();
+    let legacy = {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *PlatformConfig::DISCRIMINATOR,
+            SocialFiError::UnsupportedAccountType
+        );
+        require!(
+            data.len() == PlatformConfigV0::LEN,
+            SocialFiError::AccountAlreadyMigrated
+        );
+        PlatformConfigV0::deserialize(&mut &data[8..])?
+    };
+    require!(
+        legacy.admin == ctx.accounts.admin.key(),
+        SocialFiError::Unauthorized
+    );
+
+    let config = legacy.upgrade();
+    grow_account(
+        (&account, &ctx.accounts.admin.to_account_info(), &ctx.accounts.system_program.to_account_info()),
+        PlatformConfig::LEN,
+    )?;
+    config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: account.key(),
+        from_version: 0,
+        to_version: ACCOUNT_VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Helpers ====================
+
+type MigrationAccounts<'a, 'info> = (&'a AccountInfo<'info>, &'a AccountInfo<'info>, &'a AccountInfo<'info>);
+
+fn upgrade_account<T>(accounts: MigrationAccounts) -> Result<u8>
+where
+    T: AccountSerialize + AccountDeserialize + Versioned,
+{
+    grow_account(accounts, T::LEN)?;
+    let mut data = accounts.0.try_borrow_mut_data()?;
+    upgrade_in_place::<T>(&mut data)
+}
+
+/// Rewrite `data` (already sized for the current layout) at `ACCOUNT_VERSION`,
+/// returning the version it was stored at
+pub fn upgrade_in_place<T>(data: &mut [u8]) -> Result<u8>
+where
+    T: AccountSerialize + AccountDeserialize + Versioned,
+{
+    let mut account = T::try_deserialize(&mut &data[..])?;
+    let from_version = account.version();
+    require!(
+        from_version < ACCOUNT_VERSION,
+        SocialFiError::AccountAlreadyMigrated
+    );
+
+    account.set_version(ACCOUNT_VERSION);
+    account.try_serialize(&mut &mut data[..])?;
+    Ok(from_version)
+}
+
+/// Top up rent from the payer and zero-extend the account to `new_len`
+fn grow_account(accounts: MigrationAccounts, new_len: usize) -> Result<()> {
+    let (account, payer, system_program) = accounts;
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_due > 0 {
+        let cpi_context = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+        );
+        transfer(cpi_context, rent_due)?;
+    }
+
+    account.resize(new_len)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `UserProfile` as it was serialized before versioning
+    #[derive(AnchorSerialize)]
+    struct UserProfileV0 {
+        owner: Pubkey,
+        username: String,
+        total_tips_sent: u64,
+        total_tips_received: u64,
+        posts_count: u64,
+        followers_count: u64,
+        following_count: u64,
+        referral_code: String,
+        referred_by: Option<Pubkey>,
+        referrals_count: u64,
+        created_at: i64,
+        bump: u8,
+    }
+
+    /// `CreatorPool` as it was serialized before versioning
+    #[derive(AnchorSerialize)]
+    struct CreatorPoolV0 {
+        creator: Pubkey,
+        supply: u64,
+        holders_count: u64,
+        base_price: u64,
+        total_volume: u64,
+        created_at: i64,
+        bump: u8,
+    }
+
+    /// Bytes of a v0 account as allocated on-chain: discriminator, body, zero slack
+    fn v0_account_data<T: Discriminator>(body: &impl AnchorSerialize, allocated_len: usize) -> Vec<u8> {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        body.serialize(&mut data).unwrap();
+        assert!(data.len() <= allocated_len);
+        data.resize(allocated_len, 0);
+        data
+    }
+
+    #[test]
+    fn v0_user_profile_upgrades_with_fields_intact() {
+        let owner = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let v0 = UserProfileV0 {
+            owner,
+            username: "alice".to_string(),
+            total_tips_sent: 1_000,
+            total_tips_received: 2_000,
+            posts_count: 3,
+            followers_count: 4,
+            following_count: 5,
+            referral_code: "AbCdEfGh".to_string(),
+            referred_by: Some(referrer),
+            referrals_count: 6,
+            created_at: 1_700_000_000,
+            bump: 254,
+        };
+        // Baseline UserProfile::LEN before version/reserved were added
+        let mut data = v0_account_data::<UserProfile>(&v0, 166);
+
+        data.resize(UserProfile::LEN, 0);
+        let from_version = upgrade_in_place::<UserProfile>(&mut data).unwrap();
+        assert_eq!(from_version, 0);
+
+        let profile = UserProfile::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(profile.owner, owner);
+        assert_eq!(profile.username, "alice");
+        assert_eq!(profile.total_tips_sent, 1_000);
+        assert_eq!(profile.total_tips_received, 2_000);
+        assert_eq!(profile.posts_count, 3);
+        assert_eq!(profile.followers_count, 4);
+        assert_eq!(profile.following_count, 5);
+        assert_eq!(profile.referral_code, "AbCdEfGh");
+        assert_eq!(profile.referred_by, Some(referrer));
+        assert_eq!(profile.referrals_count, 6);
+        assert_eq!(profile.created_at, 1_700_000_000);
+        assert_eq!(profile.bump, 254);
+        assert_eq!(profile.version, ACCOUNT_VERSION);
+        assert!(profile.reserved.iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn v0_creator_pool_needs_realloc_before_it_deserializes() {
+        let creator = Pubkey::new_unique();
+        let v0 = CreatorPoolV0 {
+            creator,
+            supply: 42,
+            holders_count: 7,
+            base_price: BASE_PRICE,
+            total_volume: 123_456,
+            created_at: 1_700_000_000,
+            bump: 253,
+        };
+        // Fixed-size account: no slack, so the v1 layout cannot be read in place
+        let mut data = v0_account_data::<CreatorPool>(&v0, 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1);
+        assert!(CreatorPool::try_deserialize(&mut &data[..]).is_err());
+
+        data.resize(CreatorPool::LEN, 0);
+        assert_eq!(upgrade_in_place::<CreatorPool>(&mut data).unwrap(), 0);
+
+        let pool = CreatorPool::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(pool.creator, creator);
+        assert_eq!(pool.supply, 42);
+        assert_eq!(pool.holders_count, 7);
+        assert_eq!(pool.base_price, BASE_PRICE);
+        assert_eq!(pool.total_volume, 123_456);
+        assert_eq!(pool.bump, 253);
+        assert_eq!(pool.version, ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn migrated_account_cannot_be_migrated_again() {
+        let v0 = CreatorPoolV0 {
+            creator: Pubkey::new_unique(),
+            supply: 0,
+            holders_count: 0,
+            base_price: BASE_PRICE,
+            total_volume: 0,
+            created_at: 0,
+            bump: 255,
+        };
+        let mut data = v0_account_data::<CreatorPool>(&v0, CreatorPool::LEN);
+        upgrade_in_place::<CreatorPool>(&mut data).unwrap();
+
+        assert!(upgrade_in_place::<CreatorPool>(&mut data).is_err());
+    }
+
+    #[test]
+    fn v0_platform_config_upgrades_with_defaults() {
+        let admin = Pubkey::new_unique();
+        let fee_collector = Pubkey::new_unique();
+        let v0 = PlatformConfigV0 {
+            admin,
+            fee_collector,
+            paused: true,
+            min_liquidity_bps: 1500,
+            bump: 252,
+        };
+        let data = v0_account_data::<PlatformConfig>(&v0, PlatformConfigV0::LEN);
+        assert_eq!(data.len(), PlatformConfigV0::LEN);
+
+        let legacy = PlatformConfigV0::deserialize(&mut &data[8..]).unwrap();
+        let mut upgraded = vec![0u8; PlatformConfig::LEN];
+        legacy.upgrade().try_serialize(&mut &mut upgraded[..]).unwrap();
+
+        let config = PlatformConfig::try_deserialize(&mut &upgraded[..]).unwrap();
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.fee_collector, fee_collector);
+        assert_eq!(config.paused_modules, PAUSE_ALL);
+        assert_eq!(config.min_liquidity_bps, 1500);
+        assert_eq!(config.pending_admin, None);
+        assert_eq!(config.fee_schedule, FeeSchedule::default_schedule());
+        assert_eq!(config.bump, 252);
+        assert_eq!(config.version, ACCOUNT_VERSION);
+    }
+}
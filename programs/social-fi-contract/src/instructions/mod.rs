@@ -7,6 +7,15 @@ pub mod governance;
 pub mod marketplace;
 pub mod post;
 pub mod social;
+pub mod multisig;
+pub mod migration;
+pub mod blocklist;
+pub mod referral;
+pub mod token_tip;
+pub mod tip_stream;
+pub mod session;
+pub mod verification;
+pub mod identity;
 
 pub use platform::*;
 pub use user::*;
@@ -17,3 +26,12 @@ pub use governance::*;
 pub use marketplace::*;
 pub use post::*;
 pub use social::*;
+pub use multisig::*;
+pub use migration::*;
+pub use blocklist::*;
+pub use referral::*;
+pub use token_tip::*;
+pub use tip_stream::*;
+pub use session::*;
+pub use verification::*;
+pub use identity::*;
@@ -0,0 +1,356 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+
+// ==================== Create Multisig ====================
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Multisig::LEN,
+        seeds = [MULTISIG_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// CHECK: Vault PDA that acts as the multisig's signing authority
+    #[account(
+        seeds = [MULTISIG_VAULT_SEED, multisig.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    validate_signer_set(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    let clock = Clock::get()?;
+
+    multisig.creator = ctx.accounts.creator.key();
+    multisig.signers = signers.clone();
+    multisig.threshold = threshold;
+    multisig.transaction_count = 0;
+    multisig.vault_bump = ctx.bumps.vault;
+    multisig.bump = ctx.bumps.multisig;
+    multisig.version = ACCOUNT_VERSION;
+    multisig.signer_set_seqno = 0;
+
+    emit!(MultisigCreated {
+        multisig: ctx.accounts.multisig.key(),
+        vault: ctx.accounts.vault.key(),
+        signers,
+        threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Non-empty, duplicate-free signer list of at most `MAX_MULTISIG_SIGNERS` with a reachable threshold
+fn validate_signer_set(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+        SocialFiError::InvalidMultisigSigners
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..i].contains(signer),
+            SocialFiError::InvalidMultisigSigners
+        );
+    }
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        SocialFiError::InvalidMultisigThreshold
+    );
+    Ok(())
+}
+
+// ==================== Change Signers ====================
+
+#[derive(Accounts)]
+pub struct ChangeMultisigSigners<'info> {
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The multisig's own vault, so this only runs as an approved multisig transaction
+    #[account(
+        seeds = [MULTISIG_VAULT_SEED, multisig.key().as_ref()],
+        bump = multisig.vault_bump
+    )]
+    pub vault: Signer<'info>,
+}
+
+/// Replace the signer set and threshold. Approvals on pending transactions are tied
+/// to signer indices, so every transaction proposed under the old set becomes stale.
+pub fn change_multisig_signers(
+    ctx: Context<ChangeMultisigSigners>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_signer_set(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = signers.clone();
+    multisig.threshold = threshold;
+    multisig.signer_set_seqno = multisig
+        .signer_set_seqno
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let clock = Clock::get()?;
+    emit!(MultisigSignersChanged {
+        multisig: multisig.key(),
+        signers,
+        threshold,
+        signer_set_seqno: multisig.signer_set_seqno,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Propose Transaction ====================
+
+#[derive(Accounts)]
+#[instruction(accounts: Vec<MultisigAccountMeta>, data: Vec<u8>)]
+pub struct ProposeMultisigTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, multisig.creator.as_ref()],
+        bump = multisig.bump,
+        constraint = multisig.signer_index(&proposer.key()).is_some() @ SocialFiError::NotMultisigSigner
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigTransaction::space(accounts.len(), data.len()),
+        seeds = [
+            MULTISIG_TRANSACTION_SEED,
+            multisig.key().as_ref(),
+            &multisig.transaction_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose an instruction against this program, to be signed by the multisig vault once approved
+pub fn propose_multisig_transaction(
+    ctx: Context<ProposeMultisigTransaction>,
+    accounts: Vec<MultisigAccountMeta>,
+    data: Vec<u8>,
+) -> Result<()> {
+    require!(
+        accounts.len() <= MAX_MULTISIG_TX_ACCOUNTS && !data.is_empty() && data.len() <= MAX_MULTISIG_TX_DATA,
+        SocialFiError::InvalidMultisigTransaction
+    );
+
+    let multisig = &mut ctx.accounts.multisig;
+    let clock = Clock::get()?;
+    let index = multisig.transaction_count;
+    let proposer_index = multisig
+        .signer_index(&ctx.accounts.proposer.key())
+        .ok_or(SocialFiError::NotMultisigSigner)?;
+
+    multisig.transaction_count = multisig
+        .transaction_count
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // Proposing counts as the proposer's approval
+    let transaction = &mut ctx.accounts.transaction;
+    transaction.multisig = multisig.key();
+    transaction.proposer = ctx.accounts.proposer.key();
+    transaction.index = index;
+    transaction.accounts = accounts;
+    transaction.data = data;
+    transaction.approvals = 1 << proposer_index;
+    transaction.executed = false;
+    transaction.created_at = clock.unix_timestamp;
+    transaction.bump = ctx.bumps.transaction;
+    transaction.version = ACCOUNT_VERSION;
+    transaction.signer_set_seqno = multisig.signer_set_seqno;
+
+    emit!(MultisigTransactionProposed {
+        multisig: multisig.key(),
+        transaction: transaction.key(),
+        index,
+        proposer: ctx.accounts.proposer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Approve Transaction ====================
+
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_TRANSACTION_SEED,
+            multisig.key().as_ref(),
+            &transaction.index.to_le_bytes()
+        ],
+        bump = transaction.bump,
+        has_one = multisig,
+        constraint = !transaction.executed @ SocialFiError::TransactionAlreadyExecuted,
+        constraint = transaction.signer_set_seqno == multisig.signer_set_seqno @ SocialFiError::StaleMultisigTransaction
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+    let signer_index = ctx.accounts.multisig
+        .signer_index(&ctx.accounts.signer.key())
+        .ok_or(SocialFiError::NotMultisigSigner)?;
+
+    let transaction = &mut ctx.accounts.transaction;
+    require!(
+        !transaction.has_approved(signer_index),
+        SocialFiError::AlreadyApproved
+    );
+    transaction.approvals |= 1 << signer_index;
+
+    let clock = Clock::get()?;
+    emit!(MultisigTransactionApproved {
+        multisig: ctx.accounts.multisig.key(),
+        transaction: transaction.key(),
+        signer: ctx.accounts.signer.key(),
+        approvals: transaction.approval_count(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Execute Transaction ====================
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.creator.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_TRANSACTION_SEED,
+            multisig.key().as_ref(),
+            &transaction.index.to_le_bytes()
+        ],
+        bump = transaction.bump,
+        has_one = multisig,
+        constraint = !transaction.executed @ SocialFiError::TransactionAlreadyExecuted,
+        constraint = transaction.signer_set_seqno == multisig.signer_set_seqno @ SocialFiError::StaleMultisigTransaction
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+
+    /// CHECK: Vault PDA that signs the inner instruction
+    #[account(
+        mut,
+        seeds = [MULTISIG_VAULT_SEED, multisig.key().as_ref()],
+        bump = multisig.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    pub executor: Signer<'info>,
+}
+
+/// Execute an approved transaction. Every account in the stored instruction's metas,
+/// other than the vault, must be passed as a remaining account.
+pub fn execute_multisig_transaction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMultisigTransaction<'info>>,
+) -> Result<()> {
+    // ===== CHECKS =====
+    require!(
+        ctx.accounts.transaction.approval_count() >= ctx.accounts.multisig.threshold as u32,
+        SocialFiError::MultisigThresholdNotMet
+    );
+
+    let vault_key = ctx.accounts.vault.key();
+    let metas: Vec<AccountMeta> = ctx.accounts.transaction.accounts
+        .iter()
+        .map(|meta| AccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect();
+
+    // Every referenced account must be supplied by the executor
+    let mut account_infos = vec![ctx.accounts.vault.to_account_info()];
+    for meta in metas.iter().filter(|meta| meta.pubkey != vault_key) {
+        let info = ctx.remaining_accounts
+            .iter()
+            .find(|info| info.key == &meta.pubkey)
+            .ok_or(SocialFiError::InvalidMultisigTransaction)?;
+        account_infos.push(info.clone());
+    }
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: metas,
+        data: ctx.accounts.transaction.data.clone(),
+    };
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    // Written to the account now, not at instruction exit, so the inner
+    // instruction already sees the transaction as spent
+    let transaction = &mut ctx.accounts.transaction;
+    transaction.executed = true;
+    transaction.exit(&crate::ID)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    let multisig_key = ctx.accounts.multisig.key();
+    let vault_seeds = &[
+        MULTISIG_VAULT_SEED,
+        multisig_key.as_ref(),
+        &[ctx.accounts.multisig.vault_bump],
+    ];
+    invoke_signed(&instruction, &account_infos, &[&vault_seeds[..]])?;
+
+    let clock = Clock::get()?;
+    emit!(MultisigTransactionExecuted {
+        multisig: multisig_key,
+        transaction: ctx.accounts.transaction.key(),
+        executor: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
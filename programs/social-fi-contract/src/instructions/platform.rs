@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
 
 // ==================== Initialize Platform ====================
 
@@ -14,6 +17,24 @@ pub struct InitializePlatform<'info> {
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        init,
+        payer = admin,
+        space = PlatformStats::LEN,
+        seeds = [PLATFORM_STATS_SEED],
+        bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolParams::LEN,
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
     #[account(mut)]
     pub admin: Signer<'info>,
     
@@ -28,9 +49,23 @@ pub fn initialize_platform(
     
     config.admin = ctx.accounts.admin.key();
     config.fee_collector = fee_collector;
-    config.paused = false;
+    config.paused_modules = 0;
     config.min_liquidity_bps = 1000; // 10% default
+    config.pending_admin = None;
+    config.admin_transfer_eta = 0;
+    config.fee_schedule = FeeSchedule::default_schedule();
     config.bump = ctx.bumps.platform_config;
+    config.version = ACCOUNT_VERSION;
+    config.guardian = Pubkey::default();
+    config.referral_share_bps = DEFAULT_REFERRAL_SHARE_BPS;
+    config.identity_oracle = Pubkey::default();
+
+    reset_platform_stats(&mut ctx.accounts.platform_stats, ctx.bumps.platform_stats);
+    reset_protocol_params(
+        &mut ctx.accounts.protocol_params,
+        ctx.bumps.protocol_params,
+        Clock::get()?.unix_timestamp,
+    );
     
     Ok(())
 }
@@ -50,23 +85,71 @@ pub struct UpdatePlatform<'info> {
     pub admin: Signer<'info>,
 }
 
-pub fn pause_platform(ctx: Context<UpdatePlatform>) -> Result<()> {
-    ctx.accounts.platform_config.paused = true;
+pub fn unpause_platform(ctx: Context<UpdatePlatform>) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    let previous_paused_modules = config.paused_modules;
+    config.paused_modules = 0;
+
+    let clock = Clock::get()?;
+    emit!(PlatformUnpaused {
+        previous_paused_modules,
+        paused_modules: config.paused_modules,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
-pub fn unpause_platform(ctx: Context<UpdatePlatform>) -> Result<()> {
-    ctx.accounts.platform_config.paused = false;
+/// Pause every module whose PAUSE_* bit is set in `modules`, leaving the rest untouched
+pub fn pause_modules(ctx: Context<UpdatePlatform>, modules: u8) -> Result<()> {
+    require!(modules != 0, SocialFiError::InvalidPauseFlags);
+    let config = &mut ctx.accounts.platform_config;
+    let previous_paused_modules = config.paused_modules;
+    config.paused_modules |= modules;
+
+    let clock = Clock::get()?;
+    emit!(PlatformPaused {
+        previous_paused_modules,
+        paused_modules: config.paused_modules,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
-pub fn update_admin(ctx: Context<UpdatePlatform>, new_admin: Pubkey) -> Result<()> {
-    ctx.accounts.platform_config.admin = new_admin;
+/// Resume every module whose PAUSE_* bit is set in `modules`, leaving the rest untouched
+pub fn unpause_modules(ctx: Context<UpdatePlatform>, modules: u8) -> Result<()> {
+    require!(modules != 0, SocialFiError::InvalidPauseFlags);
+    let config = &mut ctx.accounts.platform_config;
+    let previous_paused_modules = config.paused_modules;
+    config.paused_modules &= !modules;
+
+    let clock = Clock::get()?;
+    emit!(PlatformUnpaused {
+        previous_paused_modules,
+        paused_modules: config.paused_modules,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
 pub fn update_fee_collector(ctx: Context<UpdatePlatform>, new_fee_collector: Pubkey) -> Result<()> {
-    ctx.accounts.platform_config.fee_collector = new_fee_collector;
+    let config = &mut ctx.accounts.platform_config;
+    let previous_fee_collector = config.fee_collector;
+    config.fee_collector = new_fee_collector;
+
+    let clock = Clock::get()?;
+    emit!(FeeCollectorChanged {
+        previous_fee_collector,
+        new_fee_collector,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -75,6 +158,423 @@ pub fn update_min_liquidity(ctx: Context<UpdatePlatform>, new_min_liquidity_bps:
         new_min_liquidity_bps <= 5000, // Max 50%
         crate::errors::SocialFiError::InvalidAmount
     );
-    ctx.accounts.platform_config.min_liquidity_bps = new_min_liquidity_bps;
+    let config = &mut ctx.accounts.platform_config;
+    let previous_min_liquidity_bps = config.min_liquidity_bps;
+    config.min_liquidity_bps = new_min_liquidity_bps;
+
+    let clock = Clock::get()?;
+    emit!(MinLiquidityChanged {
+        previous_min_liquidity_bps,
+        new_min_liquidity_bps,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_fee_schedule(ctx: Context<UpdatePlatform>, fee_schedule: FeeSchedule) -> Result<()> {
+    fee_schedule.validate()?;
+    let config = &mut ctx.accounts.platform_config;
+    let previous_fee_schedule = config.fee_schedule;
+    config.fee_schedule = fee_schedule;
+
+    let clock = Clock::get()?;
+    emit!(FeeScheduleChanged {
+        previous_fee_schedule,
+        new_fee_schedule: fee_schedule,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Share of each platform fee credited to the paying user's referrer
+pub fn update_referral_share(ctx: Context<UpdatePlatform>, new_referral_share_bps: u64) -> Result<()> {
+    require!(
+        new_referral_share_bps <= MAX_REFERRAL_SHARE_BPS,
+        SocialFiError::FeeTooHigh
+    );
+    let config = &mut ctx.accounts.platform_config;
+    let previous_referral_share_bps = config.referral_share_bps;
+    config.referral_share_bps = new_referral_share_bps;
+
+    let clock = Clock::get()?;
+    emit!(ReferralShareChanged {
+        previous_referral_share_bps,
+        new_referral_share_bps,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Set the emergency guardian; `Pubkey::default()` removes it
+pub fn set_guardian(ctx: Context<UpdatePlatform>, new_guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    require!(new_guardian != config.admin, SocialFiError::InvalidGuardian);
+
+    let previous_guardian = config.guardian;
+    config.guardian = new_guardian;
+
+    let clock = Clock::get()?;
+    emit!(GuardianChanged {
+        previous_guardian,
+        new_guardian,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Set the attestation service key accepted by `link_identity`; `Pubkey::default()` disables linking
+pub fn set_identity_oracle(ctx: Context<UpdatePlatform>, new_oracle: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    let previous_oracle = config.identity_oracle;
+    config.identity_oracle = new_oracle;
+
+    let clock = Clock::get()?;
+    emit!(IdentityOracleChanged {
+        previous_oracle,
+        new_oracle,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Emergency Pause ====================
+
+#[derive(Accounts)]
+pub struct PausePlatform<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.can_pause(&authority.key()) @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Admin or guardian
+    pub authority: Signer<'info>,
+}
+
+/// Pause every module. The guardian may call this, but only the admin can unpause.
+pub fn pause_platform(ctx: Context<PausePlatform>) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    let previous_paused_modules = config.paused_modules;
+    config.paused_modules = PAUSE_ALL;
+
+    let clock = Clock::get()?;
+    emit!(PlatformPaused {
+        previous_paused_modules,
+        paused_modules: config.paused_modules,
+        signer: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Admin Handover ====================
+
+pub fn propose_admin(ctx: Context<UpdatePlatform>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    require!(
+        new_admin != Pubkey::default() && new_admin != config.admin,
+        SocialFiError::InvalidPendingAdmin
+    );
+
+    let clock = Clock::get()?;
+    let accept_after = clock
+        .unix_timestamp
+        .checked_add(ADMIN_TRANSFER_DELAY)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // Re-proposing replaces any pending transfer and restarts the timelock
+    config.pending_admin = Some(new_admin);
+    config.admin_transfer_eta = accept_after;
+
+    emit!(AdminTransferProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+        accept_after,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.pending_admin.is_some() @ SocialFiError::NoPendingAdmin,
+        constraint = platform_config.pending_admin == Some(new_admin.key()) @ SocialFiError::NotPendingAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub new_admin: Signer<'info>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= config.admin_transfer_eta,
+        SocialFiError::AdminTransferTimelocked
+    );
+
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = None;
+    config.admin_transfer_eta = 0;
+
+    emit!(AdminChanged {
+        previous_admin,
+        new_admin: config.admin,
+        signer: ctx.accounts.new_admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_admin_transfer(ctx: Context<UpdatePlatform>) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    let cancelled_admin = config
+        .pending_admin
+        .ok_or(SocialFiError::NoPendingAdmin)?;
+
+    config.pending_admin = None;
+    config.admin_transfer_eta = 0;
+
+    let clock = Clock::get()?;
+    emit!(AdminTransferCancelled {
+        admin: config.admin,
+        cancelled_admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Protocol Params ====================
+
+#[derive(Accounts)]
+pub struct InitializeProtocolParams<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolParams::LEN,
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the params account for a platform initialized before `initialize_platform`
+/// created it, seeded with the compile-time defaults
+pub fn initialize_protocol_params(ctx: Context<InitializeProtocolParams>) -> Result<()> {
+    let clock = Clock::get()?;
+    reset_protocol_params(
+        &mut ctx.accounts.protocol_params,
+        ctx.bumps.protocol_params,
+        clock.unix_timestamp,
+    );
+
     Ok(())
 }
+
+fn reset_protocol_params(params: &mut ProtocolParams, bump: u8, timestamp: i64) {
+    params.base_price = BASE_PRICE;
+    params.min_offer_amount = MIN_OFFER_AMOUNT;
+    params.voting_period = VOTING_PERIOD;
+    params.min_voting_power = MIN_VOTING_POWER;
+    params.lock_multipliers_bps = ProtocolParams::DEFAULT_LOCK_MULTIPLIERS_BPS;
+    params.apy_bps = ProtocolParams::DEFAULT_APY_BPS;
+    params.updated_at = timestamp;
+    params.bump = bump;
+    params.version = ACCOUNT_VERSION;
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolParams<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
+    pub admin: Signer<'info>,
+}
+
+/// Only pools created after this call use the new base price
+pub fn update_base_price(ctx: Context<UpdateProtocolParams>, new_base_price: u64) -> Result<()> {
+    ProtocolParams::validate_base_price(new_base_price)?;
+
+    let params = &mut ctx.accounts.protocol_params;
+    let clock = Clock::get()?;
+    let previous_base_price = params.base_price;
+    params.base_price = new_base_price;
+    params.updated_at = clock.unix_timestamp;
+
+    emit!(BasePriceChanged {
+        previous_base_price,
+        new_base_price,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_min_offer_amount(ctx: Context<UpdateProtocolParams>, new_min_offer_amount: u64) -> Result<()> {
+    ProtocolParams::validate_min_offer_amount(new_min_offer_amount)?;
+
+    let params = &mut ctx.accounts.protocol_params;
+    let clock = Clock::get()?;
+    let previous_min_offer_amount = params.min_offer_amount;
+    params.min_offer_amount = new_min_offer_amount;
+    params.updated_at = clock.unix_timestamp;
+
+    emit!(MinOfferAmountChanged {
+        previous_min_offer_amount,
+        new_min_offer_amount,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Applies to proposals created after this call; open proposals keep their end time
+pub fn update_voting_params(
+    ctx: Context<UpdateProtocolParams>,
+    new_voting_period: i64,
+    new_min_voting_power: u64,
+) -> Result<()> {
+    ProtocolParams::validate_voting_params(new_voting_period, new_min_voting_power)?;
+
+    let params = &mut ctx.accounts.protocol_params;
+    let clock = Clock::get()?;
+    let previous_voting_period = params.voting_period;
+    let previous_min_voting_power = params.min_voting_power;
+    params.voting_period = new_voting_period;
+    params.min_voting_power = new_min_voting_power;
+    params.updated_at = clock.unix_timestamp;
+
+    emit!(VotingParamsChanged {
+        previous_voting_period,
+        new_voting_period,
+        previous_min_voting_power,
+        new_min_voting_power,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Rates apply to new stakes only; existing positions keep the APY they staked at
+pub fn update_staking_rates(
+    ctx: Context<UpdateProtocolParams>,
+    new_lock_multipliers_bps: [u64; LOCK_TIER_COUNT],
+    new_apy_bps: [u64; LOCK_TIER_COUNT],
+) -> Result<()> {
+    ProtocolParams::validate_staking_rates(&new_lock_multipliers_bps, &new_apy_bps)?;
+
+    let params = &mut ctx.accounts.protocol_params;
+    let clock = Clock::get()?;
+    let previous_lock_multipliers_bps = params.lock_multipliers_bps;
+    let previous_apy_bps = params.apy_bps;
+    params.lock_multipliers_bps = new_lock_multipliers_bps;
+    params.apy_bps = new_apy_bps;
+    params.updated_at = clock.unix_timestamp;
+
+    emit!(StakingRatesChanged {
+        previous_lock_multipliers_bps,
+        new_lock_multipliers_bps,
+        previous_apy_bps,
+        new_apy_bps,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Platform Stats ====================
+
+#[derive(Accounts)]
+pub struct InitializePlatformStats<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = PlatformStats::LEN,
+        seeds = [PLATFORM_STATS_SEED],
+        bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the stats PDA for a platform initialized before `initialize_platform` created it.
+/// Counters start at zero; activity before this call is not backfilled.
+pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Result<()> {
+    reset_platform_stats(&mut ctx.accounts.platform_stats, ctx.bumps.platform_stats);
+
+    Ok(())
+}
+
+fn reset_platform_stats(stats: &mut PlatformStats, bump: u8) {
+    stats.total_users = 0;
+    stats.total_pools = 0;
+    stats.total_share_volume = 0;
+    stats.total_tip_volume = 0;
+    stats.total_subscriptions = 0;
+    stats.total_groups = 0;
+    stats.total_posts = 0;
+    stats.total_staked = 0;
+    stats.total_marketplace_volume = 0;
+    stats.bump = bump;
+    stats.version = ACCOUNT_VERSION;
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::metadata::Metadata;
@@ -29,10 +30,24 @@ pub struct CreatePost<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_POSTS) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// Author's verification, if any; recorded on the post as `author_verified`
+    #[account(
+        seeds = [VERIFICATION_SEED, author.key().as_ref()],
+        bump = author_verification.bump
+    )]
+    pub author_verification: Option<Account<'info, Verification>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -57,8 +72,20 @@ pub fn create_post(ctx: Context<CreatePost>, nonce: String, uri: String) -> Resu
     post.mint = None;
     post.created_at = clock.unix_timestamp;
     post.bump = bump;
+    post.version = ACCOUNT_VERSION;
+    post.tips_received = 0;
+    post.author_verified = ctx.accounts.author_verification
+        .as_ref()
+        .is_some_and(|verification| verification.is_active(clock.unix_timestamp));
+    let post_key = post.key();
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_posts = platform_stats
+        .total_posts
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
-    msg!("Post created: {}", post.key());
+    msg!("Post created: {}", post_key);
     Ok(())
 }
 
@@ -106,6 +133,13 @@ pub struct MintPost<'info> {
     #[account(mut)]
     pub author: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_POSTS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -226,3 +260,163 @@ pub fn mint_post(ctx: Context<MintPost>, title: String, nft_metadata_uri: String
 
     Ok(())
 }
+
+// ==================== Tip Post ====================
+
+#[derive(Accounts)]
+pub struct TipPost<'info> {
+    #[account(
+        mut,
+        seeds = [POST_SEED, post.author.as_ref(), post.nonce.as_bytes()],
+        bump = post.bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, sender.key().as_ref()],
+        bump = sender_profile.bump
+    )]
+    pub sender_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, post.author.as_ref()],
+        bump = author_profile.bump
+    )]
+    pub author_profile: Account<'info, UserProfile>,
+    
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    
+    /// CHECK: Post author verified against the post
+    #[account(
+        mut,
+        address = post.author @ SocialFiError::Unauthorized
+    )]
+    pub author: AccountInfo<'info>,
+    
+    /// CHECK: Block of the sender by the post author; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, post.author.as_ref(), sender.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_TIPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the sender; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, sender.key().as_ref()],
+        bump,
+        constraint = sender_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub sender_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the author; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, post.author.as_ref()],
+        bump,
+        constraint = author_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub author_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn tip_post(ctx: Context<TipPost>, amount: u64, message: String) -> Result<()> {
+    // ===== CHECKS =====
+    require!(amount > 0, SocialFiError::InvalidAmount);
+    require!(
+        message.len() <= MAX_TIP_MESSAGE_LENGTH,
+        SocialFiError::TipMessageTooLong
+    );
+    require!(
+        ctx.accounts.sender.key() != ctx.accounts.author.key(),
+        SocialFiError::CannotTipSelf
+    );
+
+    let fee = PlatformConfig::calculate_fee(amount, ctx.accounts.platform_config.fee_schedule.tip_bps)?;
+    let author_amount = amount
+        .checked_sub(fee)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    let post = &mut ctx.accounts.post;
+    post.tips_received = post
+        .tips_received
+        .checked_add(author_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let sender_profile = &mut ctx.accounts.sender_profile;
+    sender_profile.total_tips_sent = sender_profile
+        .total_tips_sent
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let author_profile = &mut ctx.accounts.author_profile;
+    author_profile.total_tips_received = author_profile
+        .total_tips_received
+        .checked_add(author_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_tip_volume = platform_stats
+        .total_tip_volume
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.sender.to_account_info(),
+            to: ctx.accounts.author.to_account_info(),
+        },
+    );
+    transfer(cpi_context, author_amount)?;
+
+    if fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender.to_account_info(),
+                to: ctx.accounts.fee_collector.to_account_info(),
+            },
+        );
+        transfer(cpi_context, fee)?;
+    }
+
+    let clock = Clock::get()?;
+    emit!(PostTipped {
+        post: ctx.accounts.post.key(),
+        sender: ctx.accounts.sender.key(),
+        author: ctx.accounts.author.key(),
+        amount,
+        fee,
+        message,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
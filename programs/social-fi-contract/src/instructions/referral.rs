@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::instructions::user::load_program_account;
+
+// ==================== Referral Fee Split ====================
+
+/// Portion of `fee` owed to the payer's referrer. Zero when the payer has no
+/// profile or no referrer; otherwise `rewards` must be that referrer's PDA.
+pub fn referral_fee(
+    config: &PlatformConfig,
+    fee: u64,
+    payer_profile: &AccountInfo,
+    rewards: Option<&Account<ReferralRewards>>,
+) -> Result<u64> {
+    let referrer = match load_program_account::<UserProfile>(payer_profile)? {
+        Some(profile) => profile.referred_by,
+        None => None,
+    };
+    let Some(referrer) = referrer else {
+        return Ok(0);
+    };
+
+    let rewards = rewards.ok_or(SocialFiError::ReferralRewardsRequired)?;
+    require_keys_eq!(rewards.referrer, referrer, SocialFiError::ReferralRewardsRequired);
+    PlatformConfig::calculate_fee(fee, config.referral_share_bps)
+}
+
+/// Record `amount` as claimable by the referrer and move it from `from` into
+/// the rewards PDA. `signer_seeds` is empty unless `from` is a program PDA.
+pub fn pay_referral_reward<'info>(
+    rewards: &mut Account<'info, ReferralRewards>,
+    from: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    user: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    rewards.unclaimed = rewards
+        .unclaimed
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+    rewards.total_earned = rewards
+        .total_earned
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let cpi_context = CpiContext::new_with_signer(
+        system_program,
+        Transfer {
+            from,
+            to: rewards.to_account_info(),
+        },
+        signer_seeds,
+    );
+    transfer(cpi_context, amount)?;
+
+    let clock = Clock::get()?;
+    emit!(ReferralRewardAccrued {
+        referrer: rewards.referrer,
+        user,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Claim Referral Rewards ====================
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [REFERRAL_REWARDS_SEED, referrer.key().as_ref()],
+        bump = referral_rewards.bump,
+        has_one = referrer @ SocialFiError::Unauthorized
+    )]
+    pub referral_rewards: Account<'info, ReferralRewards>,
+    
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+    // ===== CHECKS =====
+    let amount = ctx.accounts.referral_rewards.unclaimed;
+    require!(amount > 0, SocialFiError::NoReferralRewards);
+
+    // ===== EFFECTS =====
+    let referral_rewards = &mut ctx.accounts.referral_rewards;
+    referral_rewards.unclaimed = 0;
+    referral_rewards.total_claimed = referral_rewards
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS =====
+    // Program-owned PDA: lamports above rent are exactly the unclaimed balance
+    **ctx.accounts.referral_rewards.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let clock = Clock::get()?;
+    emit!(ReferralRewardsClaimed {
+        referrer: ctx.accounts.referrer.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::instructions::user::update_profile_if_exists;
+
+// ==================== Create Session Key ====================
+
+#[derive(Accounts)]
+#[instruction(session_signer: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = SessionKey::LEN,
+        seeds = [SESSION_KEY_SEED, owner.key().as_ref(), session_signer.as_ref()],
+        bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorize `session_signer` for the `scope` actions until `duration` seconds from now.
+/// `allowance` lamports are escrowed to reimburse the rent the key pays on the owner's behalf.
+pub fn create_session_key(
+    ctx: Context<CreateSessionKey>,
+    session_signer: Pubkey,
+    scope: u8,
+    duration: i64,
+    allowance: u64,
+) -> Result<()> {
+    // ===== CHECKS =====
+    require!(
+        scope != 0 && scope & !SESSION_SCOPE_ALL == 0,
+        SocialFiError::InvalidSessionParams
+    );
+    require!(
+        duration > 0 && duration <= MAX_SESSION_DURATION,
+        SocialFiError::InvalidSessionParams
+    );
+    require!(
+        session_signer != ctx.accounts.owner.key(),
+        SocialFiError::InvalidSessionParams
+    );
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    let clock = Clock::get()?;
+    let expires_at = clock.unix_timestamp
+        .checked_add(duration)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let session_key = &mut ctx.accounts.session_key;
+    session_key.owner = ctx.accounts.owner.key();
+    session_key.session_signer = session_signer;
+    session_key.scope = scope;
+    session_key.expires_at = expires_at;
+    session_key.allowance = allowance;
+    session_key.created_at = clock.unix_timestamp;
+    session_key.bump = ctx.bumps.session_key;
+    session_key.version = ACCOUNT_VERSION;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.session_keys = user_profile
+        .session_keys
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    if allowance > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.session_key.to_account_info(),
+            },
+        );
+        transfer(cpi_context, allowance)?;
+    }
+
+    emit!(SessionKeyCreated {
+        owner: ctx.accounts.owner.key(),
+        session_signer,
+        scope,
+        expires_at,
+        allowance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Revoke Session Key ====================
+
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    #[account(
+        mut,
+        seeds = [SESSION_KEY_SEED, owner.key().as_ref(), session_key.session_signer.as_ref()],
+        bump = session_key.bump,
+        has_one = owner @ SocialFiError::Unauthorized,
+        close = owner
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    /// CHECK: Owner's profile; its session key count is decremented when it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub user_profile: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Revoke a session key, refunding the unused allowance and rent to the owner
+pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+    // Keys created before profiles counted them were never added
+    update_profile_if_exists(&ctx.accounts.user_profile, |profile| {
+        profile.session_keys = profile.session_keys.saturating_sub(1);
+    })?;
+
+    let clock = Clock::get()?;
+
+    emit!(SessionKeyRevoked {
+        owner: ctx.accounts.owner.key(),
+        session_signer: ctx.accounts.session_key.session_signer,
+        refunded: ctx.accounts.session_key.allowance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Session Authorization ====================
+
+/// Check that `signer` may act for `owner`: either it is the owner, or it is the
+/// unexpired session key `session_key` with `scope`.
+pub fn authorize_signer(
+    owner: &Pubkey,
+    signer: &Pubkey,
+    session_key: Option<&Account<SessionKey>>,
+    scope: u8,
+) -> Result<()> {
+    if signer == owner {
+        return Ok(());
+    }
+    let session_key = session_key.ok_or(SocialFiError::InvalidSessionKey)?;
+    require!(
+        session_key.owner == *owner && session_key.session_signer == *signer,
+        SocialFiError::InvalidSessionKey
+    );
+    let clock = Clock::get()?;
+    require!(
+        session_key.allows(scope, clock.unix_timestamp),
+        SocialFiError::SessionKeyNotAllowed
+    );
+    Ok(())
+}
+
+/// Pay back rent a session key fronted for `created`, drawing on the session allowance.
+/// No-op when the owner signed directly.
+pub fn reimburse_session_rent<'info>(
+    session_key: Option<&mut Account<'info, SessionKey>>,
+    signer: &AccountInfo<'info>,
+    created: &AccountInfo<'info>,
+) -> Result<()> {
+    let Some(session_key) = session_key else {
+        return Ok(());
+    };
+    if session_key.session_signer != signer.key() {
+        return Ok(());
+    }
+
+    let rent = created.lamports();
+    session_key.allowance = session_key.allowance
+        .checked_sub(rent)
+        .ok_or(SocialFiError::SessionAllowanceExhausted)?;
+
+    **session_key.to_account_info().try_borrow_mut_lamports()? -= rent;
+    **signer.try_borrow_mut_lamports()? += rent;
+    Ok(())
+}
@@ -4,6 +4,7 @@ use crate::state::*;
 use crate::errors::*;
 use crate::events::*;
 use crate::constants::*;
+use crate::instructions::referral::{referral_fee, pay_referral_reward};
 
 // ==================== Initialize Creator Pool ====================
 
@@ -21,6 +22,26 @@ pub struct InitializeCreatorPool<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SHARES) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -31,10 +52,18 @@ pub fn initialize_creator_pool(ctx: Context<InitializeCreatorPool>) -> Result<()
     creator_pool.creator = ctx.accounts.creator.key();
     creator_pool.supply = 0;
     creator_pool.holders_count = 0;
-    creator_pool.base_price = BASE_PRICE;
+    creator_pool.base_price = ctx.accounts.protocol_params.base_price;
+    creator_pool.sell_fee_bps = Some(ctx.accounts.platform_config.fee_schedule.share_sell_bps);
     creator_pool.total_volume = 0;
     creator_pool.created_at = clock.unix_timestamp;
     creator_pool.bump = ctx.bumps.creator_pool;
+    creator_pool.version = ACCOUNT_VERSION;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_pools = platform_stats
+        .total_pools
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     Ok(())
 }
@@ -78,10 +107,61 @@ pub struct BuyShares<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_SHARES) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    #[account(
+        seeds = [PROTOCOL_PARAMS_SEED],
+        bump = protocol_params.bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+    
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, buyer.key().as_ref()],
+        bump,
+        constraint = buyer_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub buyer_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the creator; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, creator.key().as_ref()],
+        bump,
+        constraint = creator_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub creator_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Buyer's profile, if any; when it names a referrer, `referral_rewards` must be theirs
+    #[account(
+        seeds = [USER_PROFILE_SEED, buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_profile: AccountInfo<'info>,
+    
+    /// Rewards PDA of the referrer named in `buyer_profile.referred_by`
+    #[account(
+        mut,
+        seeds = [REFERRAL_REWARDS_SEED, referral_rewards.referrer.as_ref()],
+        bump = referral_rewards.bump
+    )]
+    pub referral_rewards: Option<Account<'info, ReferralRewards>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -99,10 +179,18 @@ pub fn buy_shares(ctx: Context<BuyShares>, amount: u64, max_price_per_share: u64
         creator_pool.creator = ctx.accounts.creator.key();
         creator_pool.supply = 0;
         creator_pool.holders_count = 0;
-        creator_pool.base_price = BASE_PRICE;
+        creator_pool.base_price = ctx.accounts.protocol_params.base_price;
+        creator_pool.sell_fee_bps = Some(ctx.accounts.platform_config.fee_schedule.share_sell_bps);
         creator_pool.total_volume = 0;
         creator_pool.created_at = clock.unix_timestamp;
         creator_pool.bump = ctx.bumps.creator_pool;
+        creator_pool.version = ACCOUNT_VERSION;
+
+        let platform_stats = &mut ctx.accounts.platform_stats;
+        platform_stats.total_pools = platform_stats
+            .total_pools
+            .checked_add(1)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
     }
     
     // Calculate total cost
@@ -131,6 +219,12 @@ pub fn buy_shares(ctx: Context<BuyShares>, amount: u64, max_price_per_share: u64
         .checked_add(total_cost)
         .ok_or(SocialFiError::ArithmeticOverflow)?;
     
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_share_volume = platform_stats
+        .total_share_volume
+        .checked_add(total_cost)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+    
     if is_new_holder {
         creator_pool.holders_count = creator_pool
             .holders_count
@@ -164,6 +258,7 @@ pub fn buy_shares(ctx: Context<BuyShares>, amount: u64, max_price_per_share: u64
         let clock = Clock::get()?;
         share_holding.created_at = clock.unix_timestamp;
         share_holding.bump = ctx.bumps.share_holding;
+        share_holding.version = ACCOUNT_VERSION;
     }
 
     // ===== INTERACTIONS (External calls LAST) =====
@@ -177,6 +272,42 @@ pub fn buy_shares(ctx: Context<BuyShares>, amount: u64, max_price_per_share: u64
     );
     transfer(cpi_context, total_cost)?;
 
+    // Platform fee is charged on top of the bonding curve cost, minus the referrer's cut
+    let fee = PlatformConfig::calculate_fee(total_cost, ctx.accounts.platform_config.fee_schedule.share_buy_bps)?;
+    let referral_amount = referral_fee(
+        &ctx.accounts.platform_config,
+        fee,
+        &ctx.accounts.buyer_profile,
+        ctx.accounts.referral_rewards.as_ref(),
+    )?;
+    let protocol_fee = fee
+        .checked_sub(referral_amount)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+    if protocol_fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.fee_collector.to_account_info(),
+            },
+        );
+        transfer(cpi_context, protocol_fee)?;
+    }
+
+    if referral_amount > 0 {
+        if let Some(referral_rewards) = ctx.accounts.referral_rewards.as_mut() {
+            pay_referral_reward(
+                referral_rewards,
+                ctx.accounts.buyer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                &[],
+                ctx.accounts.buyer.key(),
+                referral_amount,
+            )?;
+        }
+    }
+
     // Emit event after successful transfer
     let avg_price = total_cost
         .checked_div(amount)
@@ -189,6 +320,7 @@ pub fn buy_shares(ctx: Context<BuyShares>, amount: u64, max_price_per_share: u64
         amount,
         price: avg_price,
         total_cost,
+        fee,
         timestamp: clock.unix_timestamp,
     });
 
@@ -230,10 +362,47 @@ pub struct SellShares<'info> {
     #[account(
         seeds = [b"platform_config"],
         bump = platform_config.bump,
-        constraint = !platform_config.paused @ crate::errors::SocialFiError::ContractPaused
+        constraint = !platform_config.is_paused(PAUSE_SHARES) @ crate::errors::SocialFiError::ContractPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
     
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the seller; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, seller.key().as_ref()],
+        bump,
+        constraint = seller_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub seller_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Seller's profile, if any; when it names a referrer, `referral_rewards` must be theirs
+    #[account(
+        seeds = [USER_PROFILE_SEED, seller.key().as_ref()],
+        bump
+    )]
+    pub seller_profile: AccountInfo<'info>,
+    
+    /// Rewards PDA of the referrer named in `seller_profile.referred_by`
+    #[account(
+        mut,
+        seeds = [REFERRAL_REWARDS_SEED, referral_rewards.referrer.as_ref()],
+        bump = referral_rewards.bump
+    )]
+    pub referral_rewards: Option<Account<'info, ReferralRewards>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -250,13 +419,10 @@ pub fn sell_shares(ctx: Context<SellShares>, amount: u64, min_price_per_share: u
 
     let creator_pool = &mut ctx.accounts.creator_pool;
     
-    // Calculate sell return (after 10% fee)
+    // Calculate gross sell return, then the pool's sell fee
     let total_return = creator_pool.calculate_sell_return(amount)?;
-    let fee = total_return
-        .checked_mul(SELL_FEE_BPS)
-        .ok_or(SocialFiError::ArithmeticOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+    let sell_fee_bps = creator_pool.sell_fee_bps(&ctx.accounts.platform_config.fee_schedule);
+    let fee = PlatformConfig::calculate_fee(total_return, sell_fee_bps)?;
     
     let seller_receives = total_return
         .checked_sub(fee)
@@ -271,10 +437,10 @@ pub fn sell_shares(ctx: Context<SellShares>, amount: u64, min_price_per_share: u
         SocialFiError::SlippageExceeded
     );
     
-    // Check pool has sufficient liquidity
+    // Check pool has sufficient liquidity (seller payout and fee both leave the vault)
     let pool_balance = ctx.accounts.pool_vault.lamports();
     require!(
-        pool_balance >= seller_receives,
+        pool_balance >= total_return,
         SocialFiError::InsufficientLiquidity
     );
     
@@ -287,7 +453,7 @@ pub fn sell_shares(ctx: Context<SellShares>, amount: u64, min_price_per_share: u
         .ok_or(SocialFiError::ArithmeticUnderflow)?;
     
     require!(
-        pool_balance.saturating_sub(seller_receives) >= min_liquidity,
+        pool_balance.saturating_sub(total_return) >= min_liquidity,
         SocialFiError::MinimumLiquidityRequired
     );
     
@@ -302,6 +468,12 @@ pub fn sell_shares(ctx: Context<SellShares>, amount: u64, min_price_per_share: u
         .total_volume
         .checked_add(total_return)
         .ok_or(SocialFiError::ArithmeticOverflow)?;
+    
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_share_volume = platform_stats
+        .total_share_volume
+        .checked_add(total_return)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     // Update share holding
     let share_holding = &mut ctx.accounts.share_holding;
@@ -344,6 +516,47 @@ pub fn sell_shares(ctx: Context<SellShares>, amount: u64, min_price_per_share: u
         signer_seeds,
     )?;
 
+    let referral_amount = referral_fee(
+        &ctx.accounts.platform_config,
+        fee,
+        &ctx.accounts.seller_profile,
+        ctx.accounts.referral_rewards.as_ref(),
+    )?;
+    let protocol_fee = fee
+        .checked_sub(referral_amount)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+    if protocol_fee > 0 {
+        let fee_ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.pool_vault.key(),
+            &ctx.accounts.fee_collector.key(),
+            protocol_fee,
+        );
+        
+        anchor_lang::solana_program::program::invoke_signed(
+            &fee_ix,
+            &[
+                ctx.accounts.pool_vault.to_account_info(),
+                ctx.accounts.fee_collector.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+    }
+
+    if referral_amount > 0 {
+        if let Some(referral_rewards) = ctx.accounts.referral_rewards.as_mut() {
+            pay_referral_reward(
+                referral_rewards,
+                ctx.accounts.pool_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                signer_seeds,
+                ctx.accounts.seller.key(),
+                referral_amount,
+            )?;
+        }
+    }
+
     // Calculate average price for event
     let avg_price = total_return
         .checked_div(amount)
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::constants::*;
+use crate::instructions::session::{authorize_signer, reimburse_session_rent};
+use crate::instructions::user::{load_program_account, update_profile_if_exists};
 
 // ==================== Follow User ====================
 
@@ -9,19 +12,39 @@ use crate::constants::*;
 pub struct FollowUser<'info> {
     #[account(
         init,
-        payer = follower,
+        payer = signer,
         space = Follow::LEN,
         seeds = [FOLLOW_SEED, follower.key().as_ref(), following.key().as_ref()],
         bump
     )]
     pub follow: Account<'info, Follow>,
     
+    /// CHECK: Wallet doing the following; authorized through `signer`
+    pub follower: UncheckedAccount<'info>,
+    
+    /// Owner wallet or one of its session keys; pays rent
     #[account(mut)]
-    pub follower: Signer<'info>,
+    pub signer: Signer<'info>,
+    
+    /// Required when `signer` is a session key
+    #[account(
+        mut,
+        seeds = [SESSION_KEY_SEED, follower.key().as_ref(), signer.key().as_ref()],
+        bump = session_key.bump
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
     
     /// CHECK: The user being followed (just their pubkey)
     pub following: UncheckedAccount<'info>,
     
+    /// CHECK: Block of the actor by the followed user; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, following.key().as_ref(), follower.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+    
     /// Follower's profile (optional - to update count)
     #[account(
         mut,
@@ -38,10 +61,24 @@ pub struct FollowUser<'info> {
     )]
     pub following_profile: Account<'info, UserProfile>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
+    authorize_signer(
+        &ctx.accounts.follower.key(),
+        &ctx.accounts.signer.key(),
+        ctx.accounts.session_key.as_ref(),
+        SESSION_SCOPE_FOLLOW,
+    )?;
+
     let follow = &mut ctx.accounts.follow;
     let clock = Clock::get()?;
 
@@ -49,11 +86,18 @@ pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
     follow.following = ctx.accounts.following.key();
     follow.created_at = clock.unix_timestamp;
     follow.bump = ctx.bumps.follow;
+    follow.version = ACCOUNT_VERSION;
 
     // Update profile counts
     ctx.accounts.follower_profile.following_count = ctx.accounts.follower_profile.following_count.saturating_add(1);
     ctx.accounts.following_profile.followers_count = ctx.accounts.following_profile.followers_count.saturating_add(1);
 
+    reimburse_session_rent(
+        ctx.accounts.session_key.as_mut(),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.follow.to_account_info(),
+    )?;
+
     msg!("User {} followed {}", ctx.accounts.follower.key(), ctx.accounts.following.key());
     Ok(())
 }
@@ -77,25 +121,39 @@ pub struct UnfollowUser<'info> {
     /// CHECK: The user being unfollowed
     pub following: UncheckedAccount<'info>,
     
+    /// CHECK: Follower's profile; following count is updated if it exists
     #[account(
         mut,
         seeds = [USER_PROFILE_SEED, follower.key().as_ref()],
-        bump = follower_profile.bump,
+        bump
     )]
-    pub follower_profile: Account<'info, UserProfile>,
+    pub follower_profile: AccountInfo<'info>,
     
+    /// CHECK: Followed user's profile; follower count is updated if it exists.
+    /// A closed profile keeps its followers' `Follow` accounts open until they unfollow.
     #[account(
         mut,
         seeds = [USER_PROFILE_SEED, following.key().as_ref()],
-        bump = following_profile.bump,
+        bump
     )]
-    pub following_profile: Account<'info, UserProfile>,
+    pub following_profile: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn unfollow_user(ctx: Context<UnfollowUser>) -> Result<()> {
     // Update profile counts
-    ctx.accounts.follower_profile.following_count = ctx.accounts.follower_profile.following_count.saturating_sub(1);
-    ctx.accounts.following_profile.followers_count = ctx.accounts.following_profile.followers_count.saturating_sub(1);
+    update_profile_if_exists(&ctx.accounts.follower_profile, |profile| {
+        profile.following_count = profile.following_count.saturating_sub(1);
+    })?;
+    update_profile_if_exists(&ctx.accounts.following_profile, |profile| {
+        profile.followers_count = profile.followers_count.saturating_sub(1);
+    })?;
 
     msg!("User {} unfollowed {}", ctx.accounts.follower.key(), ctx.accounts.following.key());
     Ok(())
@@ -107,24 +165,58 @@ pub fn unfollow_user(ctx: Context<UnfollowUser>) -> Result<()> {
 pub struct LikePost<'info> {
     #[account(
         init,
-        payer = user,
+        payer = signer,
         space = Like::LEN,
         seeds = [LIKE_SEED, user.key().as_ref(), post.key().as_ref()],
         bump
     )]
     pub like: Account<'info, Like>,
     
+    /// CHECK: Wallet liking the post; authorized through `signer`
+    pub user: UncheckedAccount<'info>,
+    
+    /// Owner wallet or one of its session keys; pays rent
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub signer: Signer<'info>,
+    
+    /// Required when `signer` is a session key
+    #[account(
+        mut,
+        seeds = [SESSION_KEY_SEED, user.key().as_ref(), signer.key().as_ref()],
+        bump = session_key.bump
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
     
     /// The post being liked
     #[account(mut)]
     pub post: Account<'info, Post>,
     
+    /// CHECK: Block of the actor by the post author; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, post.author.as_ref(), user.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn like_post(ctx: Context<LikePost>) -> Result<()> {
+    authorize_signer(
+        &ctx.accounts.user.key(),
+        &ctx.accounts.signer.key(),
+        ctx.accounts.session_key.as_ref(),
+        SESSION_SCOPE_LIKE,
+    )?;
+
     let like = &mut ctx.accounts.like;
     let clock = Clock::get()?;
 
@@ -132,6 +224,13 @@ pub fn like_post(ctx: Context<LikePost>) -> Result<()> {
     like.post = ctx.accounts.post.key();
     like.created_at = clock.unix_timestamp;
     like.bump = ctx.bumps.like;
+    like.version = ACCOUNT_VERSION;
+
+    reimburse_session_rent(
+        ctx.accounts.session_key.as_mut(),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.like.to_account_info(),
+    )?;
 
     msg!("User {} liked post {}", ctx.accounts.user.key(), ctx.accounts.post.key());
     Ok(())
@@ -155,6 +254,13 @@ pub struct UnlikePost<'info> {
     pub user: Signer<'info>,
     
     pub post: Account<'info, Post>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn unlike_post(ctx: Context<UnlikePost>) -> Result<()> {
@@ -168,23 +274,49 @@ pub fn unlike_post(ctx: Context<UnlikePost>) -> Result<()> {
 pub struct CreateRepost<'info> {
     #[account(
         init,
-        payer = user,
+        payer = signer,
         space = Repost::LEN,
         seeds = [REPOST_SEED, user.key().as_ref(), original_post.key().as_ref()],
         bump
     )]
     pub repost: Account<'info, Repost>,
     
+    /// CHECK: Wallet reposting; authorized through `signer`
+    pub user: UncheckedAccount<'info>,
+    
+    /// Owner wallet or one of its session keys; pays rent
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub signer: Signer<'info>,
+    
+    /// Required when `signer` is a session key
+    #[account(
+        mut,
+        seeds = [SESSION_KEY_SEED, user.key().as_ref(), signer.key().as_ref()],
+        bump = session_key.bump
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
     
     /// The post being reposted
     pub original_post: Account<'info, Post>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn create_repost(ctx: Context<CreateRepost>) -> Result<()> {
+    authorize_signer(
+        &ctx.accounts.user.key(),
+        &ctx.accounts.signer.key(),
+        ctx.accounts.session_key.as_ref(),
+        SESSION_SCOPE_REPOST,
+    )?;
+
     let repost = &mut ctx.accounts.repost;
     let clock = Clock::get()?;
 
@@ -192,6 +324,13 @@ pub fn create_repost(ctx: Context<CreateRepost>) -> Result<()> {
     repost.original_post = ctx.accounts.original_post.key();
     repost.created_at = clock.unix_timestamp;
     repost.bump = ctx.bumps.repost;
+    repost.version = ACCOUNT_VERSION;
+
+    reimburse_session_rent(
+        ctx.accounts.session_key.as_mut(),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.repost.to_account_info(),
+    )?;
 
     msg!("User {} reposted post {}", ctx.accounts.user.key(), ctx.accounts.original_post.key());
     Ok(())
@@ -204,19 +343,46 @@ pub fn create_repost(ctx: Context<CreateRepost>) -> Result<()> {
 pub struct CreateComment<'info> {
     #[account(
         init,
-        payer = author,
+        payer = signer,
         space = Comment::LEN,
         seeds = [COMMENT_SEED, post.key().as_ref(), author.key().as_ref(), &nonce.to_le_bytes()],
         bump
     )]
     pub comment: Account<'info, Comment>,
     
+    /// CHECK: Wallet commenting; authorized through `signer`
+    pub author: UncheckedAccount<'info>,
+    
+    /// Owner wallet or one of its session keys; pays rent
     #[account(mut)]
-    pub author: Signer<'info>,
+    pub signer: Signer<'info>,
+    
+    /// Required when `signer` is a session key
+    #[account(
+        mut,
+        seeds = [SESSION_KEY_SEED, author.key().as_ref(), signer.key().as_ref()],
+        bump = session_key.bump
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
     
     /// The post being commented on
     pub post: Account<'info, Post>,
     
+    /// CHECK: Block of the actor by the post author; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, post.author.as_ref(), author.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -229,6 +395,12 @@ pub fn create_comment(ctx: Context<CreateComment>, _nonce: u64, content: String)
         !content.trim().is_empty(),
         SocialFiError::EmptyContent
     );
+    authorize_signer(
+        &ctx.accounts.author.key(),
+        &ctx.accounts.signer.key(),
+        ctx.accounts.session_key.as_ref(),
+        SESSION_SCOPE_COMMENT,
+    )?;
 
     let comment = &mut ctx.accounts.comment;
     let clock = Clock::get()?;
@@ -238,7 +410,166 @@ pub fn create_comment(ctx: Context<CreateComment>, _nonce: u64, content: String)
     comment.content = content;
     comment.created_at = clock.unix_timestamp;
     comment.bump = ctx.bumps.comment;
+    comment.version = ACCOUNT_VERSION;
+
+    reimburse_session_rent(
+        ctx.accounts.session_key.as_mut(),
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.comment.to_account_info(),
+    )?;
 
     msg!("User {} commented on post {}", ctx.accounts.author.key(), ctx.accounts.post.key());
     Ok(())
 }
+
+// ==================== Block User ====================
+
+#[derive(Accounts)]
+pub struct BlockUser<'info> {
+    #[account(
+        init,
+        payer = blocker,
+        space = Block::LEN,
+        seeds = [BLOCK_SEED, blocker.key().as_ref(), blocked.key().as_ref()],
+        bump
+    )]
+    pub block: Account<'info, Block>,
+    
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+    
+    /// CHECK: The user being blocked; receives the rent of a removed follow
+    #[account(mut)]
+    pub blocked: UncheckedAccount<'info>,
+    
+    /// CHECK: The blocked user's follow of the blocker; closed if it exists
+    #[account(
+        mut,
+        seeds = [FOLLOW_SEED, blocked.key().as_ref(), blocker.key().as_ref()],
+        bump
+    )]
+    pub existing_follow: AccountInfo<'info>,
+    
+    /// CHECK: The blocked user's tip stream to the blocker; closed and refunded if it exists
+    #[account(
+        mut,
+        seeds = [TIP_STREAM_SEED, blocked.key().as_ref(), blocker.key().as_ref()],
+        bump
+    )]
+    pub existing_tip_stream: AccountInfo<'info>,
+    
+    /// CHECK: Blocker's profile; follower count is updated if it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, blocker.key().as_ref()],
+        bump
+    )]
+    pub blocker_profile: AccountInfo<'info>,
+    
+    /// CHECK: Blocked user's profile; following count is updated if it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, blocked.key().as_ref()],
+        bump
+    )]
+    pub blocked_profile: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Block a user and drop their follow in the same transaction. A tip stream from the
+/// blocked user is stopped too: its whole escrow, including periods not yet cranked,
+/// goes back to the blocked user. Not gated by the social pause so users can always
+/// protect themselves.
+pub fn block_user(ctx: Context<BlockUser>) -> Result<()> {
+    let blocker = ctx.accounts.blocker.key();
+    let blocked = ctx.accounts.blocked.key();
+    require!(blocker != blocked, SocialFiError::CannotBlockSelf);
+
+    let clock = Clock::get()?;
+    let block = &mut ctx.accounts.block;
+    block.blocker = blocker;
+    block.blocked = blocked;
+    block.created_at = clock.unix_timestamp;
+    block.bump = ctx.bumps.block;
+    block.version = ACCOUNT_VERSION;
+
+    let existing_follow = &ctx.accounts.existing_follow;
+    let removed_follow = !existing_follow.data_is_empty() && existing_follow.owner == &crate::ID;
+    if removed_follow {
+        let blocked_info = ctx.accounts.blocked.to_account_info();
+        **blocked_info.try_borrow_mut_lamports()? += existing_follow.lamports();
+        **existing_follow.try_borrow_mut_lamports()? = 0;
+        existing_follow.assign(&System::id());
+        existing_follow.resize(0)?;
+
+        update_profile_if_exists(&ctx.accounts.blocker_profile, |profile| {
+            profile.followers_count = profile.followers_count.saturating_sub(1);
+        })?;
+        update_profile_if_exists(&ctx.accounts.blocked_profile, |profile| {
+            profile.following_count = profile.following_count.saturating_sub(1);
+        })?;
+    }
+
+    let existing_tip_stream = &ctx.accounts.existing_tip_stream;
+    if let Some(tip_stream) = load_program_account::<TipStream>(existing_tip_stream)? {
+        let blocked_info = ctx.accounts.blocked.to_account_info();
+        **blocked_info.try_borrow_mut_lamports()? += existing_tip_stream.lamports();
+        **existing_tip_stream.try_borrow_mut_lamports()? = 0;
+        existing_tip_stream.assign(&System::id());
+        existing_tip_stream.resize(0)?;
+
+        update_profile_if_exists(&ctx.accounts.blocker_profile, |profile| {
+            profile.active_tip_streams = profile.active_tip_streams.saturating_sub(1);
+        })?;
+        update_profile_if_exists(&ctx.accounts.blocked_profile, |profile| {
+            profile.active_tip_streams = profile.active_tip_streams.saturating_sub(1);
+        })?;
+
+        emit!(TipStreamCancelled {
+            stream: existing_tip_stream.key(),
+            sender: blocked,
+            recipient: blocker,
+            refunded: tip_stream.balance,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(UserBlocked {
+        blocker,
+        blocked,
+        removed_follow,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Unblock User ====================
+
+#[derive(Accounts)]
+pub struct UnblockUser<'info> {
+    #[account(
+        mut,
+        close = blocker,
+        seeds = [BLOCK_SEED, blocker.key().as_ref(), block.blocked.as_ref()],
+        bump = block.bump,
+        has_one = blocker,
+    )]
+    pub block: Account<'info, Block>,
+    
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+}
+
+pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(UserUnblocked {
+        blocker: ctx.accounts.blocker.key(),
+        blocked: ctx.accounts.block.blocked,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
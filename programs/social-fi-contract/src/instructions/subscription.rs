@@ -4,6 +4,7 @@ use crate::state::*;
 use crate::errors::*;
 use crate::events::*;
 use crate::constants::*;
+use crate::instructions::referral::{referral_fee, pay_referral_reward};
 
 // ==================== Create Subscription Tier ====================
 
@@ -26,6 +27,13 @@ pub struct CreateSubscriptionTier<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SUBSCRIPTIONS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -60,6 +68,7 @@ pub fn create_subscription_tier(
     subscription_tier.subscriber_count = 0;
     subscription_tier.created_at = clock.unix_timestamp;
     subscription_tier.bump = ctx.bumps.subscription_tier;
+    subscription_tier.version = ACCOUNT_VERSION;
 
     emit!(SubscriptionTierCreated {
         creator: ctx.accounts.creator.key(),
@@ -114,6 +123,58 @@ pub struct Subscribe<'info> {
     #[account(mut)]
     pub creator: AccountInfo<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SUBSCRIPTIONS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the subscriber; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, subscriber.key().as_ref()],
+        bump,
+        constraint = subscriber_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub subscriber_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the creator; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, creator.key().as_ref()],
+        bump,
+        constraint = creator_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub creator_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    /// CHECK: Subscriber's profile, if any; when it names a referrer, `referral_rewards` must be theirs
+    #[account(
+        seeds = [USER_PROFILE_SEED, subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscriber_profile: AccountInfo<'info>,
+    
+    /// Rewards PDA of the referrer named in `subscriber_profile.referred_by`
+    #[account(
+        mut,
+        seeds = [REFERRAL_REWARDS_SEED, referral_rewards.referrer.as_ref()],
+        bump = referral_rewards.bump
+    )]
+    pub referral_rewards: Option<Account<'info, ReferralRewards>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -125,6 +186,10 @@ pub fn subscribe(ctx: Context<Subscribe>) -> Result<()> {
     // ===== CHECKS =====
     let price = subscription_tier.price;
     let tier_id = subscription_tier.tier_id;
+    let fee = PlatformConfig::calculate_fee(price, ctx.accounts.platform_config.fee_schedule.subscription_bps)?;
+    let creator_amount = price
+        .checked_sub(fee)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
 
     // Calculate end date
     let duration_seconds = subscription_tier
@@ -148,6 +213,7 @@ pub fn subscribe(ctx: Context<Subscribe>) -> Result<()> {
     subscription.auto_renew = false;
     subscription.created_at = clock.unix_timestamp;
     subscription.bump = ctx.bumps.subscription;
+    subscription.version = ACCOUNT_VERSION;
 
     // Update subscriber count
     let subscription_tier = &mut ctx.accounts.subscription_tier;
@@ -156,6 +222,12 @@ pub fn subscribe(ctx: Context<Subscribe>) -> Result<()> {
         .checked_add(1)
         .ok_or(SocialFiError::ArithmeticOverflow)?;
 
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_subscriptions = platform_stats
+        .total_subscriptions
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
     // ===== INTERACTIONS (External calls LAST) =====
     // Transfer payment to creator
     let cpi_context = CpiContext::new(
@@ -165,12 +237,47 @@ pub fn subscribe(ctx: Context<Subscribe>) -> Result<()> {
             to: ctx.accounts.creator.to_account_info(),
         },
     );
-    transfer(cpi_context, price)?;
+    transfer(cpi_context, creator_amount)?;
+
+    let referral_amount = referral_fee(
+        &ctx.accounts.platform_config,
+        fee,
+        &ctx.accounts.subscriber_profile,
+        ctx.accounts.referral_rewards.as_ref(),
+    )?;
+    let protocol_fee = fee
+        .checked_sub(referral_amount)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+    if protocol_fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.subscriber.to_account_info(),
+                to: ctx.accounts.fee_collector.to_account_info(),
+            },
+        );
+        transfer(cpi_context, protocol_fee)?;
+    }
+
+    if referral_amount > 0 {
+        if let Some(referral_rewards) = ctx.accounts.referral_rewards.as_mut() {
+            pay_referral_reward(
+                referral_rewards,
+                ctx.accounts.subscriber.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                &[],
+                ctx.accounts.subscriber.key(),
+                referral_amount,
+            )?;
+        }
+    }
 
     emit!(UserSubscribed {
         subscriber: ctx.accounts.subscriber.key(),
         creator: ctx.accounts.creator.key(),
         tier_id: subscription_tier.tier_id,
+        fee,
         start_date: clock.unix_timestamp,
         end_date,
         timestamp: clock.unix_timestamp,
@@ -198,6 +305,13 @@ pub struct CancelSubscription<'info> {
     
     #[account(mut)]
     pub subscriber: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SUBSCRIPTIONS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
@@ -0,0 +1,428 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::instructions::user::update_profile_if_exists;
+
+// ==================== Create Tip Stream ====================
+
+#[derive(Accounts)]
+pub struct CreateTipStream<'info> {
+    #[account(
+        init,
+        payer = sender,
+        space = TipStream::LEN,
+        seeds = [TIP_STREAM_SEED, sender.key().as_ref(), recipient.key().as_ref()],
+        bump
+    )]
+    pub tip_stream: Account<'info, TipStream>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, sender.key().as_ref()],
+        bump = sender_profile.bump
+    )]
+    pub sender_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, recipient.key().as_ref()],
+        bump = recipient_profile.bump
+    )]
+    pub recipient_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Recipient address verified through PDA
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Block of the sender by the recipient; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, recipient.key().as_ref(), sender.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_TIPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Blocklist entry for the sender; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, sender.key().as_ref()],
+        bump,
+        constraint = sender_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub sender_blocked: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pledge `amount_per_period` every `period` seconds, escrowing `deposit` lamports up front.
+/// The first payment falls due one period after creation.
+pub fn create_tip_stream(
+    ctx: Context<CreateTipStream>,
+    amount_per_period: u64,
+    period: i64,
+    deposit: u64,
+) -> Result<()> {
+    // ===== CHECKS =====
+    require!(amount_per_period > 0, SocialFiError::InvalidAmount);
+    require!(period >= MIN_TIP_STREAM_PERIOD, SocialFiError::InvalidTipStreamPeriod);
+    require!(deposit >= amount_per_period, SocialFiError::InsufficientBalance);
+    require!(
+        ctx.accounts.sender.key() != ctx.accounts.recipient.key(),
+        SocialFiError::CannotTipSelf
+    );
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    let clock = Clock::get()?;
+    let tip_stream = &mut ctx.accounts.tip_stream;
+    tip_stream.sender = ctx.accounts.sender.key();
+    tip_stream.recipient = ctx.accounts.recipient.key();
+    tip_stream.amount_per_period = amount_per_period;
+    tip_stream.period = period;
+    tip_stream.balance = deposit;
+    tip_stream.next_payment_at = clock.unix_timestamp
+        .checked_add(period)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+    tip_stream.total_paid = 0;
+    tip_stream.created_at = clock.unix_timestamp;
+    tip_stream.bump = ctx.bumps.tip_stream;
+    tip_stream.version = ACCOUNT_VERSION;
+
+    // Both profiles must outlive the stream, since cranking pays through them
+    let sender_profile = &mut ctx.accounts.sender_profile;
+    sender_profile.active_tip_streams = sender_profile
+        .active_tip_streams
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+    let recipient_profile = &mut ctx.accounts.recipient_profile;
+    recipient_profile.active_tip_streams = recipient_profile
+        .active_tip_streams
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.sender.to_account_info(),
+            to: ctx.accounts.tip_stream.to_account_info(),
+        },
+    );
+    transfer(cpi_context, deposit)?;
+
+    emit!(TipStreamCreated {
+        stream: ctx.accounts.tip_stream.key(),
+        sender: ctx.accounts.sender.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount_per_period,
+        period,
+        deposit,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Crank Tip Stream ====================
+
+#[derive(Accounts)]
+pub struct CrankTipStream<'info> {
+    #[account(
+        mut,
+        seeds = [TIP_STREAM_SEED, tip_stream.sender.as_ref(), tip_stream.recipient.as_ref()],
+        bump = tip_stream.bump
+    )]
+    pub tip_stream: Account<'info, TipStream>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, tip_stream.sender.as_ref()],
+        bump = sender_profile.bump
+    )]
+    pub sender_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, tip_stream.recipient.as_ref()],
+        bump = recipient_profile.bump
+    )]
+    pub recipient_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Recipient verified against the stream
+    #[account(
+        mut,
+        address = tip_stream.recipient @ SocialFiError::Unauthorized
+    )]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_TIPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    /// CHECK: Blocklist entry for the sender; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, tip_stream.sender.as_ref()],
+        bump,
+        constraint = sender_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub sender_blocked: AccountInfo<'info>,
+
+    /// CHECK: Blocklist entry for the recipient; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, tip_stream.recipient.as_ref()],
+        bump,
+        constraint = recipient_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub recipient_blocked: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+
+    /// Anyone may crank a stream
+    pub cranker: Signer<'info>,
+}
+
+/// Release every due period the escrow can cover
+pub fn crank_tip_stream(ctx: Context<CrankTipStream>) -> Result<()> {
+    // ===== CHECKS =====
+    let clock = Clock::get()?;
+    let tip_stream = &ctx.accounts.tip_stream;
+    require!(clock.unix_timestamp >= tip_stream.next_payment_at, SocialFiError::TipStreamNotDue);
+
+    let payment = DuePayment::at(tip_stream, clock.unix_timestamp, ctx.accounts.platform_config.fee_schedule.tip_bps)?;
+    require!(payment.periods > 0, SocialFiError::TipStreamDepleted);
+    let DuePayment { periods, amount, fee, recipient_amount } = payment;
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    payment.apply(&mut ctx.accounts.tip_stream)?;
+
+    let sender_profile = &mut ctx.accounts.sender_profile;
+    sender_profile.total_tips_sent = sender_profile
+        .total_tips_sent
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let recipient_profile = &mut ctx.accounts.recipient_profile;
+    recipient_profile.total_tips_received = recipient_profile
+        .total_tips_received
+        .checked_add(recipient_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_tip_volume = platform_stats
+        .total_tip_volume
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    // The stream PDA is program-owned, so escrow moves by direct lamport accounting
+    **ctx.accounts.tip_stream.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += recipient_amount;
+    **ctx.accounts.fee_collector.to_account_info().try_borrow_mut_lamports()? += fee;
+
+    emit!(TipStreamPaid {
+        stream: ctx.accounts.tip_stream.key(),
+        sender: ctx.accounts.tip_stream.sender,
+        recipient: ctx.accounts.tip_stream.recipient,
+        periods,
+        amount,
+        fee,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Cancel Tip Stream ====================
+
+#[derive(Accounts)]
+pub struct CancelTipStream<'info> {
+    #[account(
+        mut,
+        seeds = [TIP_STREAM_SEED, sender.key().as_ref(), tip_stream.recipient.as_ref()],
+        bump = tip_stream.bump,
+        has_one = sender @ SocialFiError::Unauthorized,
+        close = sender
+    )]
+    pub tip_stream: Account<'info, TipStream>,
+
+    /// CHECK: Sender's profile; updated when it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, sender.key().as_ref()],
+        bump
+    )]
+    pub sender_profile: AccountInfo<'info>,
+
+    /// CHECK: Recipient's profile; updated when it exists
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, tip_stream.recipient.as_ref()],
+        bump
+    )]
+    pub recipient_profile: AccountInfo<'info>,
+
+    /// CHECK: Recipient verified against the stream
+    #[account(
+        mut,
+        address = tip_stream.recipient @ SocialFiError::Unauthorized
+    )]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    /// CHECK: Blocklist entry for the recipient; a blocklisted recipient is not paid
+    #[account(
+        seeds = [BLOCKED_SEED, tip_stream.recipient.as_ref()],
+        bump
+    )]
+    pub recipient_blocked: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+}
+
+/// Stop the stream. Periods already due are paid to the recipient first, as a crank
+/// would; the remaining escrow and the rent go back to the sender.
+pub fn cancel_tip_stream(ctx: Context<CancelTipStream>) -> Result<()> {
+    // ===== CHECKS =====
+    let clock = Clock::get()?;
+    let payment = if ctx.accounts.recipient_blocked.data_is_empty() {
+        DuePayment::at(&ctx.accounts.tip_stream, clock.unix_timestamp, ctx.accounts.platform_config.fee_schedule.tip_bps)?
+    } else {
+        DuePayment::default()
+    };
+    let DuePayment { periods, amount, fee, recipient_amount } = payment;
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    payment.apply(&mut ctx.accounts.tip_stream)?;
+
+    // Streams created before profiles counted them were never added
+    update_profile_if_exists(&ctx.accounts.sender_profile, |profile| {
+        profile.total_tips_sent = profile.total_tips_sent.saturating_add(amount);
+        profile.active_tip_streams = profile.active_tip_streams.saturating_sub(1);
+    })?;
+    update_profile_if_exists(&ctx.accounts.recipient_profile, |profile| {
+        profile.total_tips_received = profile.total_tips_received.saturating_add(recipient_amount);
+        profile.active_tip_streams = profile.active_tip_streams.saturating_sub(1);
+    })?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_tip_volume = platform_stats
+        .total_tip_volume
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    // Settle the due periods; `close = sender` then refunds the rest
+    if amount > 0 {
+        **ctx.accounts.tip_stream.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
+        **ctx.accounts.fee_collector.try_borrow_mut_lamports()? += fee;
+
+        emit!(TipStreamPaid {
+            stream: ctx.accounts.tip_stream.key(),
+            sender: ctx.accounts.tip_stream.sender,
+            recipient: ctx.accounts.tip_stream.recipient,
+            periods,
+            amount,
+            fee,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let tip_stream = &ctx.accounts.tip_stream;
+    emit!(TipStreamCancelled {
+        stream: tip_stream.key(),
+        sender: tip_stream.sender,
+        recipient: tip_stream.recipient,
+        refunded: tip_stream.balance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Stream Settlement ====================
+
+/// Periods a stream owes at a point in time, and how the payment splits
+#[derive(Clone, Copy, Default)]
+struct DuePayment {
+    periods: u64,
+    amount: u64,
+    fee: u64,
+    recipient_amount: u64,
+}
+
+impl DuePayment {
+    fn at(tip_stream: &TipStream, now: i64, tip_bps: u64) -> Result<Self> {
+        let periods = tip_stream.payable_periods(now)?;
+        let amount = tip_stream.amount_per_period
+            .checked_mul(periods)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+        let fee = PlatformConfig::calculate_fee(amount, tip_bps)?;
+        let recipient_amount = amount
+            .checked_sub(fee)
+            .ok_or(SocialFiError::ArithmeticUnderflow)?;
+        Ok(Self { periods, amount, fee, recipient_amount })
+    }
+
+    /// Draw the payment from the escrow and move the schedule past the paid periods
+    fn apply(&self, tip_stream: &mut TipStream) -> Result<()> {
+        let elapsed = tip_stream.period
+            .checked_mul(self.periods as i64)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+        tip_stream.balance = tip_stream.balance
+            .checked_sub(self.amount)
+            .ok_or(SocialFiError::ArithmeticUnderflow)?;
+        tip_stream.next_payment_at = tip_stream.next_payment_at
+            .checked_add(elapsed)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+        tip_stream.total_paid = tip_stream.total_paid
+            .checked_add(self.amount)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+
+// ==================== Add Tip Mint ====================
+
+#[derive(Accounts)]
+pub struct AddTipMint<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = TipMint::LEN,
+        seeds = [TIP_MINT_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub tip_mint: Account<'info, TipMint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_tip_mint(ctx: Context<AddTipMint>) -> Result<()> {
+    let tip_mint = &mut ctx.accounts.tip_mint;
+    let clock = Clock::get()?;
+
+    tip_mint.mint = ctx.accounts.mint.key();
+    tip_mint.total_tipped = 0;
+    tip_mint.tip_count = 0;
+    tip_mint.added_by = ctx.accounts.admin.key();
+    tip_mint.added_at = clock.unix_timestamp;
+    tip_mint.bump = ctx.bumps.tip_mint;
+    tip_mint.version = ACCOUNT_VERSION;
+
+    emit!(TipMintAdded {
+        mint: tip_mint.mint,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Remove Tip Mint ====================
+
+#[derive(Accounts)]
+pub struct RemoveTipMint<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [TIP_MINT_SEED, tip_mint.mint.as_ref()],
+        bump = tip_mint.bump,
+        close = admin
+    )]
+    pub tip_mint: Account<'info, TipMint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn remove_tip_mint(ctx: Context<RemoveTipMint>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(TipMintRemoved {
+        mint: ctx.accounts.tip_mint.mint,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Send Token Tip ====================
+
+#[derive(Accounts)]
+pub struct SendTokenTip<'info> {
+    #[account(
+        seeds = [USER_PROFILE_SEED, sender.key().as_ref()],
+        bump = sender_profile.bump
+    )]
+    pub sender_profile: Account<'info, UserProfile>,
+
+    #[account(
+        seeds = [USER_PROFILE_SEED, recipient.key().as_ref()],
+        bump = recipient_profile.bump
+    )]
+    pub recipient_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Recipient address verified through PDA
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Block of the sender by the recipient; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, recipient.key().as_ref(), sender.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Allow-list entry for the mint; also accumulates per-mint totals
+    #[account(
+        mut,
+        seeds = [TIP_MINT_SEED, mint.key().as_ref()],
+        bump = tip_mint.bump
+    )]
+    pub tip_mint: Account<'info, TipMint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender,
+        token::token_program = token_program
+    )]
+    pub sender_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_TIPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = mint,
+        associated_token::authority = fee_collector,
+        associated_token::token_program = token_program
+    )]
+    pub fee_collector_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Blocklist entry for the sender; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, sender.key().as_ref()],
+        bump,
+        constraint = sender_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub sender_blocked: AccountInfo<'info>,
+
+    /// CHECK: Blocklist entry for the recipient; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, recipient.key().as_ref()],
+        bump,
+        constraint = recipient_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub recipient_blocked: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Tip in an allow-listed SPL token. Works with both the Token and Token-2022 programs.
+pub fn send_token_tip(ctx: Context<SendTokenTip>, amount: u64) -> Result<()> {
+    // ===== CHECKS =====
+    require!(amount > 0, SocialFiError::InvalidAmount);
+    require!(
+        ctx.accounts.sender.key() != ctx.accounts.recipient.key(),
+        SocialFiError::CannotTipSelf
+    );
+
+    let fee = PlatformConfig::calculate_fee(amount, ctx.accounts.platform_config.fee_schedule.tip_bps)?;
+    let recipient_amount = amount
+        .checked_sub(fee)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+    // ===== EFFECTS (Update state BEFORE external calls) =====
+    let tip_mint = &mut ctx.accounts.tip_mint;
+    tip_mint.total_tipped = tip_mint
+        .total_tipped
+        .checked_add(amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+    tip_mint.tip_count = tip_mint
+        .tip_count
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // ===== INTERACTIONS (External calls LAST) =====
+    let decimals = ctx.accounts.mint.decimals;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        ),
+        recipient_amount,
+        decimals,
+    )?;
+
+    if fee > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.sender_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.fee_collector_token_account.to_account_info(),
+                    authority: ctx.accounts.sender.to_account_info(),
+                },
+            ),
+            fee,
+            decimals,
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    emit!(TokenTipSent {
+        sender: ctx.accounts.sender.key(),
+        recipient: ctx.accounts.recipient.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        fee,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
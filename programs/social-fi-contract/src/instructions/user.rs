@@ -4,6 +4,7 @@ use crate::state::*;
 use crate::errors::*;
 use crate::events::*;
 use crate::constants::*;
+use crate::username::{normalize_username, username_seed};
 
 // ==================== Initialize User ====================
 
@@ -19,38 +20,175 @@ pub struct InitializeUser<'info> {
     )]
     pub user_profile: Account<'info, UserProfile>,
     
+    /// Claim on the username; an existing entry means the name is taken
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UsernameRegistry::LEN,
+        seeds = [USERNAME_REGISTRY_SEED, username_seed(&username).as_bytes()],
+        bump
+    )]
+    pub username_registry: Account<'info, UsernameRegistry>,
+    
+    /// The new user's own code, so others can name them as referrer. Kept when a
+    /// profile closes, so re-creating the profile reuses it.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferralCode::LEN,
+        seeds = [REFERRAL_CODE_SEED, generate_referral_code(&user.key()).as_bytes()],
+        bump
+    )]
+    pub own_referral_code: Account<'info, ReferralCode>,
+    
+    /// Accrues this user's earnings as a referrer; kept when a profile closes
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferralRewards::LEN,
+        seeds = [REFERRAL_REWARDS_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub own_referral_rewards: Account<'info, ReferralRewards>,
+    
+    /// Code entry of the referrer; required when a referral code is given
+    pub referrer_code: Option<Account<'info, ReferralCode>>,
+    
+    /// Referrer's profile; required when a referral code is given
+    #[account(mut)]
+    pub referrer_profile: Option<Account<'info, UserProfile>>,
+    
     #[account(mut)]
     pub user: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_user(ctx: Context<InitializeUser>, username: String) -> Result<()> {
+pub fn initialize_user(
+    ctx: Context<InitializeUser>,
+    username: String,
+    referral_code: Option<String>,
+) -> Result<()> {
+    let username = normalize_username(&username)?;
     require!(
-        username.len() <= MAX_USERNAME_LENGTH,
-        SocialFiError::UsernameTooLong
+        ctx.accounts.username_registry.is_available_to(&ctx.accounts.user_profile.key()),
+        SocialFiError::UsernameAlreadyTaken
     );
-    
+
+    let user_key = ctx.accounts.user.key();
     require!(
-        username.chars().all(|c| c.is_alphanumeric() || c == '_'),
-        SocialFiError::InvalidUsername
+        ctx.accounts.own_referral_code.is_available_to(&user_key),
+        SocialFiError::ReferralCodeTaken
     );
-
-    let user_profile = &mut ctx.accounts.user_profile;
     let clock = Clock::get()?;
 
-    user_profile.owner = ctx.accounts.user.key();
+    // Resolve the referrer through their ReferralCode PDA
+    let referrer = match referral_code.as_ref() {
+        Some(code) => {
+            let referrer_code = ctx.accounts.referrer_code
+                .as_ref()
+                .ok_or(SocialFiError::InvalidReferralCode)?;
+            let referrer_profile = ctx.accounts.referrer_profile
+                .as_ref()
+                .ok_or(SocialFiError::InvalidReferralCode)?;
+            require!(
+                referrer_code.code == *code && referrer_code.profile == referrer_profile.key(),
+                SocialFiError::InvalidReferralCode
+            );
+            require!(referrer_code.owner != user_key, SocialFiError::SelfReferral);
+            Some(referrer_code.owner)
+        }
+        None => None,
+    };
+
+    let own_code = generate_referral_code(&user_key);
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.owner = user_key;
     user_profile.username = username.clone();
     user_profile.total_tips_sent = 0;
     user_profile.total_tips_received = 0;
     user_profile.posts_count = 0;
     user_profile.followers_count = 0;
     user_profile.following_count = 0;
-    user_profile.referral_code = generate_referral_code(&ctx.accounts.user.key());
-    user_profile.referred_by = None;
+    user_profile.referral_code = own_code.clone();
+    user_profile.referred_by = referrer;
     user_profile.referrals_count = 0;
     user_profile.created_at = clock.unix_timestamp;
     user_profile.bump = ctx.bumps.user_profile;
+    user_profile.version = ACCOUNT_VERSION;
+    user_profile.display_name = String::new();
+    user_profile.bio = String::new();
+    user_profile.avatar_uri = String::new();
+    user_profile.banner_uri = String::new();
+    user_profile.website = String::new();
+    user_profile.primary_username_mint = Pubkey::default();
+    user_profile.open_listings = 0;
+    user_profile.active_tip_streams = 0;
+    user_profile.session_keys = 0;
+
+    let profile_key = ctx.accounts.user_profile.key();
+    claim_username(
+        &mut ctx.accounts.username_registry,
+        &username,
+        profile_key,
+        user_key,
+        ctx.bumps.username_registry,
+        clock.unix_timestamp,
+    );
+
+    let own_referral_code = &mut ctx.accounts.own_referral_code;
+    own_referral_code.code = own_code;
+    own_referral_code.owner = user_key;
+    own_referral_code.profile = profile_key;
+    own_referral_code.created_at = clock.unix_timestamp;
+    own_referral_code.bump = ctx.bumps.own_referral_code;
+    own_referral_code.version = ACCOUNT_VERSION;
+
+    // Rewards earned under an earlier profile stay claimable
+    let own_referral_rewards = &mut ctx.accounts.own_referral_rewards;
+    if own_referral_rewards.referrer == Pubkey::default() {
+        own_referral_rewards.referrer = user_key;
+        own_referral_rewards.unclaimed = 0;
+        own_referral_rewards.total_earned = 0;
+        own_referral_rewards.total_claimed = 0;
+        own_referral_rewards.bump = ctx.bumps.own_referral_rewards;
+        own_referral_rewards.version = ACCOUNT_VERSION;
+    }
+
+    if let (Some(referrer), Some(referrer_profile)) = (referrer, ctx.accounts.referrer_profile.as_mut()) {
+        referrer_profile.referrals_count = referrer_profile
+            .referrals_count
+            .checked_add(1)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+        emit!(UserReferred {
+            user: user_key,
+            referrer,
+            referral_code: referral_code.unwrap_or_default(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_users = platform_stats
+        .total_users
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
 
     emit!(UserInitialized {
         user: ctx.accounts.user.key(),
@@ -61,11 +199,456 @@ pub fn initialize_user(ctx: Context<InitializeUser>, username: String) -> Result
     Ok(())
 }
 
-fn generate_referral_code(pubkey: &Pubkey) -> String {
+pub fn generate_referral_code(pubkey: &Pubkey) -> String {
     let bytes = pubkey.to_bytes();
     bs58::encode(&bytes[..6]).into_string()
 }
 
+fn claim_username(
+    registry: &mut UsernameRegistry,
+    username: &str,
+    profile: Pubkey,
+    owner: Pubkey,
+    bump: u8,
+    timestamp: i64,
+) {
+    registry.username = username.to_string();
+    registry.profile = profile;
+    registry.owner = owner;
+    registry.claimed_at = timestamp;
+    registry.bump = bump;
+    registry.version = ACCOUNT_VERSION;
+}
+
+// ==================== Register Referral Code ====================
+
+#[derive(Accounts)]
+pub struct RegisterReferralCode<'info> {
+    #[account(
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = ReferralCode::LEN,
+        seeds = [REFERRAL_CODE_SEED, user_profile.referral_code.as_bytes()],
+        bump
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+    
+    /// Accrues this user's earnings as a referrer
+    #[account(
+        init,
+        payer = owner,
+        space = ReferralRewards::LEN,
+        seeds = [REFERRAL_REWARDS_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub referral_rewards: Account<'info, ReferralRewards>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Publish the referral code and open the rewards account of a profile created before referrals were wired up
+pub fn register_referral_code(ctx: Context<RegisterReferralCode>) -> Result<()> {
+    let clock = Clock::get()?;
+    let referral_code = &mut ctx.accounts.referral_code;
+
+    referral_code.code = ctx.accounts.user_profile.referral_code.clone();
+    referral_code.owner = ctx.accounts.owner.key();
+    referral_code.profile = ctx.accounts.user_profile.key();
+    referral_code.created_at = clock.unix_timestamp;
+    referral_code.bump = ctx.bumps.referral_code;
+    referral_code.version = ACCOUNT_VERSION;
+
+    let referral_rewards = &mut ctx.accounts.referral_rewards;
+    referral_rewards.referrer = ctx.accounts.owner.key();
+    referral_rewards.unclaimed = 0;
+    referral_rewards.total_earned = 0;
+    referral_rewards.total_claimed = 0;
+    referral_rewards.bump = ctx.bumps.referral_rewards;
+    referral_rewards.version = ACCOUNT_VERSION;
+
+    Ok(())
+}
+
+// ==================== Change Username ====================
+
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsername<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    /// CHECK: Registry entry of the current username; released when held by this profile.
+    /// Profiles created before the registry existed may not have one.
+    #[account(
+        mut,
+        seeds = [USERNAME_REGISTRY_SEED, username_seed(&user_profile.username).as_bytes()],
+        bump
+    )]
+    pub previous_registry: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = UsernameRegistry::LEN,
+        seeds = [USERNAME_REGISTRY_SEED, username_seed(&new_username).as_bytes()],
+        bump
+    )]
+    pub username_registry: Account<'info, UsernameRegistry>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn change_username(ctx: Context<ChangeUsername>, new_username: String) -> Result<()> {
+    // ===== CHECKS =====
+    let new_username = normalize_username(&new_username)?;
+    // Renaming to the current name would release the claim being taken
+    require!(
+        ctx.accounts.previous_registry.key() != ctx.accounts.username_registry.key(),
+        SocialFiError::UsernameAlreadyTaken
+    );
+    let profile_key = ctx.accounts.user_profile.key();
+    require!(
+        ctx.accounts.username_registry.is_available_to(&profile_key),
+        SocialFiError::UsernameAlreadyTaken
+    );
+
+    // ===== EFFECTS =====
+    let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
+    claim_username(
+        &mut ctx.accounts.username_registry,
+        &new_username,
+        profile_key,
+        owner,
+        ctx.bumps.username_registry,
+        clock.unix_timestamp,
+    );
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    let previous_username = std::mem::replace(&mut user_profile.username, new_username.clone());
+
+    // ===== INTERACTIONS =====
+    release_username(
+        &ctx.accounts.previous_registry,
+        &profile_key,
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    emit!(UsernameChanged {
+        user: owner,
+        previous_username,
+        new_username,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Close a registry entry if `profile` holds it, returning its rent to `destination`
+pub fn release_username<'info>(
+    registry: &AccountInfo<'info>,
+    profile: &Pubkey,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    if registry.data_is_empty() || registry.owner != &crate::ID {
+        return Ok(());
+    }
+    let entry = UsernameRegistry::try_deserialize(&mut &registry.try_borrow_data()?[..])?;
+    if entry.profile != *profile {
+        return Ok(());
+    }
+
+    let lamports = registry.lamports();
+    **registry.try_borrow_mut_lamports()? -= lamports;
+    **destination.try_borrow_mut_lamports()? += lamports;
+    registry.assign(&anchor_lang::system_program::ID);
+    registry.resize(0)?;
+    Ok(())
+}
+
+// ==================== Register Username ====================
+
+#[derive(Accounts)]
+pub struct RegisterUsername<'info> {
+    #[account(
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = UsernameRegistry::LEN,
+        seeds = [USERNAME_REGISTRY_SEED, username_seed(&user_profile.username).as_bytes()],
+        bump
+    )]
+    pub username_registry: Account<'info, UsernameRegistry>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim the current username of a profile created before the registry existed.
+/// Legacy names that do not normalize must be changed with `change_username` instead.
+pub fn register_username(ctx: Context<RegisterUsername>) -> Result<()> {
+    // Invalid names all map to the empty seed, so they must not claim it
+    let username = normalize_username(&ctx.accounts.user_profile.username)?;
+    let profile_key = ctx.accounts.user_profile.key();
+    require!(
+        ctx.accounts.username_registry.is_available_to(&profile_key),
+        SocialFiError::UsernameAlreadyTaken
+    );
+
+    let clock = Clock::get()?;
+    claim_username(
+        &mut ctx.accounts.username_registry,
+        &username,
+        profile_key,
+        ctx.accounts.owner.key(),
+        ctx.bumps.username_registry,
+        clock.unix_timestamp,
+    );
+
+    Ok(())
+}
+
+// ==================== Close User Profile ====================
+
+#[derive(Accounts)]
+pub struct CloseUserProfile<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized,
+        constraint = user_profile.open_listings == 0 @ SocialFiError::ProfileHasOpenListings,
+        constraint = user_profile.following_count == 0 @ SocialFiError::ProfileHasFollows,
+        constraint = user_profile.active_tip_streams == 0 @ SocialFiError::ProfileHasTipStreams,
+        constraint = user_profile.session_keys == 0 @ SocialFiError::ProfileHasSessionKeys,
+        close = owner
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    /// CHECK: Registry entry of the username; released when held by this profile
+    #[account(
+        mut,
+        seeds = [USERNAME_REGISTRY_SEED, username_seed(&user_profile.username).as_bytes()],
+        bump
+    )]
+    pub username_registry: AccountInfo<'info>,
+    
+    /// CHECK: Owner's stake position; must not hold tokens
+    #[account(
+        seeds = [STAKE_POSITION_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub stake_position: AccountInfo<'info>,
+    
+    /// CHECK: Owner's creator pool; must have no shares outstanding
+    #[account(
+        seeds = [CREATOR_POOL_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub creator_pool: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+pub fn close_user_profile(ctx: Context<CloseUserProfile>) -> Result<()> {
+    // ===== CHECKS =====
+    if let Some(stake_position) = load_program_account::<StakePosition>(&ctx.accounts.stake_position)? {
+        require!(stake_position.amount == 0, SocialFiError::ProfileHasActiveStake);
+    }
+    if let Some(creator_pool) = load_program_account::<CreatorPool>(&ctx.accounts.creator_pool)? {
+        require!(creator_pool.supply == 0, SocialFiError::ProfileHasCreatorSupply);
+    }
+
+    // ===== INTERACTIONS =====
+    let profile_key = ctx.accounts.user_profile.key();
+    release_username(
+        &ctx.accounts.username_registry,
+        &profile_key,
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(UserProfileClosed {
+        user: ctx.accounts.owner.key(),
+        profile: profile_key,
+        username: ctx.accounts.user_profile.username.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Apply `update` to a profile that may have been closed
+pub fn update_profile_if_exists(info: &AccountInfo, update: impl FnOnce(&mut UserProfile)) -> Result<()> {
+    if info.data_is_empty() || info.owner != &crate::ID {
+        return Ok(());
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+    update(&mut profile);
+    profile.try_serialize(&mut &mut data[..])
+}
+
+/// Deserialize an account of this program, or `None` if it was never created
+pub fn load_program_account<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() || info.owner != &crate::ID {
+        return Ok(None);
+    }
+    Ok(Some(T::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+// ==================== Update Profile ====================
+
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, owner.key().as_ref()],
+        bump = user_profile.bump,
+        has_one = owner @ SocialFiError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_SOCIAL) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the rich profile fields; `None` leaves a field unchanged and an empty string clears it
+pub fn update_profile(
+    ctx: Context<UpdateProfile>,
+    display_name: Option<String>,
+    bio: Option<String>,
+    avatar_uri: Option<String>,
+    banner_uri: Option<String>,
+    website: Option<String>,
+) -> Result<()> {
+    // ===== CHECKS =====
+    let limits = [
+        (&display_name, MAX_DISPLAY_NAME_LENGTH),
+        (&bio, MAX_BIO_LENGTH),
+        (&avatar_uri, MAX_URI_LENGTH),
+        (&banner_uri, MAX_URI_LENGTH),
+        (&website, MAX_WEBSITE_LENGTH),
+    ];
+    for (field, max_len) in limits {
+        if let Some(value) = field {
+            require!(value.len() <= max_len, SocialFiError::ProfileFieldTooLong);
+        }
+    }
+
+    // ===== EFFECTS =====
+    let user_profile = &mut ctx.accounts.user_profile;
+    if let Some(display_name) = display_name {
+        user_profile.display_name = display_name;
+    }
+    if let Some(bio) = bio {
+        user_profile.bio = bio;
+    }
+    if let Some(avatar_uri) = avatar_uri {
+        user_profile.avatar_uri = avatar_uri;
+    }
+    if let Some(banner_uri) = banner_uri {
+        user_profile.banner_uri = banner_uri;
+    }
+    if let Some(website) = website {
+        user_profile.website = website;
+    }
+    let new_len = user_profile.space();
+
+    // ===== INTERACTIONS =====
+    // Resize before Anchor serializes the profile on exit, settling rent with the owner
+    let profile_info = ctx.accounts.user_profile.to_account_info();
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let old_len = profile_info.data_len();
+    let rent_exempt = Rent::get()?.minimum_balance(new_len);
+
+    if new_len > old_len {
+        let rent_due = rent_exempt.saturating_sub(profile_info.lamports());
+        if rent_due > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: owner_info.clone(),
+                    to: profile_info.clone(),
+                },
+            );
+            transfer(cpi_context, rent_due)?;
+        }
+        profile_info.resize(new_len)?;
+    } else if new_len < old_len {
+        profile_info.resize(new_len)?;
+        let refund = profile_info.lamports().saturating_sub(rent_exempt);
+        if refund > 0 {
+            **profile_info.try_borrow_mut_lamports()? -= refund;
+            **owner_info.try_borrow_mut_lamports()? += refund;
+        }
+    }
+
+    let user_profile = &ctx.accounts.user_profile;
+    let clock = Clock::get()?;
+    emit!(ProfileUpdated {
+        user: user_profile.owner,
+        display_name: user_profile.display_name.clone(),
+        bio: user_profile.bio.clone(),
+        avatar_uri: user_profile.avatar_uri.clone(),
+        banner_uri: user_profile.banner_uri.clone(),
+        website: user_profile.website.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 // ==================== Send Tip ====================
 
 #[derive(Accounts)]
@@ -91,6 +674,51 @@ pub struct SendTip<'info> {
     #[account(mut)]
     pub recipient: AccountInfo<'info>,
     
+    /// CHECK: Block of the actor by the recipient; must not exist
+    #[account(
+        seeds = [BLOCK_SEED, recipient.key().as_ref(), sender.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ SocialFiError::BlockedByUser
+    )]
+    pub block: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_TIPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the sender; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, sender.key().as_ref()],
+        bump,
+        constraint = sender_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub sender_blocked: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the recipient; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, recipient.key().as_ref()],
+        bump,
+        constraint = recipient_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub recipient_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -102,6 +730,11 @@ pub fn send_tip(ctx: Context<SendTip>, amount: u64) -> Result<()> {
         SocialFiError::CannotTipSelf
     );
 
+    let fee = PlatformConfig::calculate_fee(amount, ctx.accounts.platform_config.fee_schedule.tip_bps)?;
+    let recipient_amount = amount
+        .checked_sub(fee)
+        .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
     // ===== EFFECTS (Update state BEFORE external calls) =====
     let sender_profile = &mut ctx.accounts.sender_profile;
     let recipient_profile = &mut ctx.accounts.recipient_profile;
@@ -113,6 +746,12 @@ pub fn send_tip(ctx: Context<SendTip>, amount: u64) -> Result<()> {
     
     recipient_profile.total_tips_received = recipient_profile
         .total_tips_received
+        .checked_add(recipient_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_tip_volume = platform_stats
+        .total_tip_volume
         .checked_add(amount)
         .ok_or(SocialFiError::ArithmeticOverflow)?;
 
@@ -124,15 +763,235 @@ pub fn send_tip(ctx: Context<SendTip>, amount: u64) -> Result<()> {
             to: ctx.accounts.recipient.to_account_info(),
         },
     );
-    transfer(cpi_context, amount)?;
+    transfer(cpi_context, recipient_amount)?;
+
+    if fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender.to_account_info(),
+                to: ctx.accounts.fee_collector.to_account_info(),
+            },
+        );
+        transfer(cpi_context, fee)?;
+    }
 
     let clock = Clock::get()?;
     emit!(TipSent {
         sender: ctx.accounts.sender.key(),
         recipient: ctx.accounts.recipient.key(),
         amount,
+        fee,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
+
+// ==================== Send Tips Batch ====================
+
+#[derive(Accounts)]
+pub struct SendTipsBatch<'info> {
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, sender.key().as_ref()],
+        bump = sender_profile.bump
+    )]
+    pub sender_profile: Account<'info, UserProfile>,
+    
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_TIPS) @ crate::errors::SocialFiError::ContractPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// CHECK: Protocol fee recipient verified against platform config
+    #[account(
+        mut,
+        address = platform_config.fee_collector @ SocialFiError::InvalidFeeCollector
+    )]
+    pub fee_collector: AccountInfo<'info>,
+    
+    /// CHECK: Blocklist entry for the sender; must not exist
+    #[account(
+        seeds = [BLOCKED_SEED, sender.key().as_ref()],
+        bump,
+        constraint = sender_blocked.data_is_empty() @ SocialFiError::WalletBlocked
+    )]
+    pub sender_blocked: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_STATS_SEED],
+        bump = platform_stats.bump
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Tip up to `MAX_TIP_BATCH_SIZE` recipients at once. For each entry of `tips`, in order,
+/// pass four remaining accounts: the recipient wallet (mut), its profile PDA (mut), its
+/// blocklist PDA and the recipient's block of the sender. Any invalid entry fails the whole batch.
+pub fn send_tips_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SendTipsBatch<'info>>,
+    tips: Vec<BatchTip>,
+) -> Result<()> {
+    // ===== CHECKS =====
+    require!(
+        !tips.is_empty() && tips.len() <= MAX_TIP_BATCH_SIZE,
+        SocialFiError::InvalidTipBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == tips.len() * 4,
+        SocialFiError::InvalidTipBatchAccounts
+    );
+
+    let sender_key = ctx.accounts.sender.key();
+    let tip_bps = ctx.accounts.platform_config.fee_schedule.tip_bps;
+    let clock = Clock::get()?;
+    let mut total_amount: u64 = 0;
+    let mut total_fee: u64 = 0;
+
+    for (tip, accounts) in tips.iter().zip(ctx.remaining_accounts.chunks_exact(4)) {
+        let (recipient, recipient_profile, recipient_blocked, block) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3]);
+
+        require!(tip.amount > 0, SocialFiError::InvalidAmount);
+        require!(recipient.key() == tip.recipient, SocialFiError::InvalidTipBatchAccounts);
+        require!(tip.recipient != sender_key, SocialFiError::CannotTipSelf);
+
+        let (blocked_key, _) = Pubkey::find_program_address(
+            &[BLOCKED_SEED, tip.recipient.as_ref()],
+            &crate::ID,
+        );
+        require!(recipient_blocked.key() == blocked_key, SocialFiError::InvalidTipBatchAccounts);
+        require!(recipient_blocked.data_is_empty(), SocialFiError::WalletBlocked);
+
+        let (block_key, _) = Pubkey::find_program_address(
+            &[BLOCK_SEED, tip.recipient.as_ref(), sender_key.as_ref()],
+            &crate::ID,
+        );
+        require!(block.key() == block_key, SocialFiError::InvalidTipBatchAccounts);
+        require!(block.data_is_empty(), SocialFiError::BlockedByUser);
+
+        let fee = PlatformConfig::calculate_fee(tip.amount, tip_bps)?;
+        let recipient_amount = tip.amount
+            .checked_sub(fee)
+            .ok_or(SocialFiError::ArithmeticUnderflow)?;
+
+        // ===== EFFECTS =====
+        require!(recipient_profile.owner == &crate::ID, SocialFiError::InvalidTipBatchAccounts);
+        {
+            let mut data = recipient_profile.try_borrow_mut_data()?;
+            let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+            let expected_profile = Pubkey::create_program_address(
+                &[USER_PROFILE_SEED, tip.recipient.as_ref(), &[profile.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| SocialFiError::InvalidTipBatchAccounts)?;
+            require!(
+                recipient_profile.key() == expected_profile && profile.owner == tip.recipient,
+                SocialFiError::InvalidTipBatchAccounts
+            );
+            profile.total_tips_received = profile
+                .total_tips_received
+                .checked_add(recipient_amount)
+                .ok_or(SocialFiError::ArithmeticOverflow)?;
+            profile.try_serialize(&mut &mut data[..])?;
+        }
+
+        total_amount = total_amount
+            .checked_add(tip.amount)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+        total_fee = total_fee
+            .checked_add(fee)
+            .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+        // ===== INTERACTIONS =====
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender.to_account_info(),
+                to: recipient.clone(),
+            },
+        );
+        transfer(cpi_context, recipient_amount)?;
+
+        emit!(TipSent {
+            sender: sender_key,
+            recipient: tip.recipient,
+            amount: tip.amount,
+            fee,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let sender_profile = &mut ctx.accounts.sender_profile;
+    sender_profile.total_tips_sent = sender_profile
+        .total_tips_sent
+        .checked_add(total_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.total_tip_volume = platform_stats
+        .total_tip_volume
+        .checked_add(total_amount)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    // Fees from the whole batch go out in one transfer
+    if total_fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender.to_account_info(),
+                to: ctx.accounts.fee_collector.to_account_info(),
+            },
+        );
+        transfer(cpi_context, total_fee)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn referral_code_owned_by(owner: Pubkey) -> ReferralCode {
+        ReferralCode {
+            code: generate_referral_code(&owner),
+            owner,
+            profile: Pubkey::new_unique(),
+            created_at: 1_700_000_000,
+            bump: 255,
+            version: ACCOUNT_VERSION,
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn colliding_wallet_cannot_take_over_referral_code() {
+        let first = Pubkey::new_from_array([7; 32]);
+        let mut second_bytes = [7; 32];
+        second_bytes[31] = 8;
+        let second = Pubkey::new_from_array(second_bytes);
+        // Both wallets share the 6-byte prefix the code is derived from
+        assert_eq!(generate_referral_code(&first), generate_referral_code(&second));
+
+        let code = referral_code_owned_by(first);
+        assert!(!code.is_available_to(&second));
+        assert!(code.is_available_to(&first));
+    }
+
+    #[test]
+    fn unclaimed_referral_code_is_available() {
+        let mut code = referral_code_owned_by(Pubkey::new_unique());
+        code.owner = Pubkey::default();
+        assert!(code.is_available_to(&Pubkey::new_unique()));
+    }
+}
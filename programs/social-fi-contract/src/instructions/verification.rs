@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+
+// ==================== Add Attester ====================
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct AddAttester<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Attester::LEN,
+        seeds = [ATTESTER_SEED, authority.as_ref()],
+        bump
+    )]
+    pub attester: Account<'info, Attester>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_attester(ctx: Context<AddAttester>, authority: Pubkey) -> Result<()> {
+    let attester = &mut ctx.accounts.attester;
+    let clock = Clock::get()?;
+
+    attester.authority = authority;
+    attester.added_by = ctx.accounts.admin.key();
+    attester.added_at = clock.unix_timestamp;
+    attester.issued_count = 0;
+    attester.bump = ctx.bumps.attester;
+    attester.version = ACCOUNT_VERSION;
+
+    emit!(AttesterAdded {
+        attester: authority,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Remove Attester ====================
+
+#[derive(Accounts)]
+pub struct RemoveAttester<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.admin == admin.key() @ SocialFiError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [ATTESTER_SEED, attester.authority.as_ref()],
+        bump = attester.bump,
+        close = admin
+    )]
+    pub attester: Account<'info, Attester>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+/// Stop an attester from issuing. Verifications it already issued stay valid until revoked or expired.
+pub fn remove_attester(ctx: Context<RemoveAttester>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(AttesterRemoved {
+        attester: ctx.accounts.attester.authority,
+        signer: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Issue Verification ====================
+
+#[derive(Accounts)]
+pub struct IssueVerification<'info> {
+    #[account(
+        mut,
+        seeds = [ATTESTER_SEED, authority.key().as_ref()],
+        bump = attester.bump
+    )]
+    pub attester: Account<'info, Attester>,
+
+    /// Profile being verified
+    #[account(
+        seeds = [USER_PROFILE_SEED, user_profile.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Verification::LEN,
+        seeds = [VERIFICATION_SEED, user_profile.owner.as_ref()],
+        bump
+    )]
+    pub verification: Account<'info, Verification>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Verify a profile as `kind` until `expires_at`. Re-issuing renews or replaces a
+/// verification unless another attester's is still active. Admin revocations are final.
+pub fn issue_verification(ctx: Context<IssueVerification>, kind: u8, expires_at: i64) -> Result<()> {
+    // ===== CHECKS =====
+    require!(
+        matches!(
+            kind,
+            VERIFICATION_KIND_IDENTITY | VERIFICATION_KIND_ORGANIZATION | VERIFICATION_KIND_CREATOR
+        ),
+        SocialFiError::InvalidVerificationKind
+    );
+    let clock = Clock::get()?;
+    require!(expires_at > clock.unix_timestamp, SocialFiError::InvalidVerificationExpiry);
+
+    let authority = ctx.accounts.authority.key();
+    let verification = &ctx.accounts.verification;
+    require!(!verification.admin_revoked, SocialFiError::VerificationRevokedByAdmin);
+    require!(
+        verification.attester == Pubkey::default()
+            || verification.attester == authority
+            || !verification.is_active(clock.unix_timestamp),
+        SocialFiError::VerificationAlreadyActive
+    );
+
+    // ===== EFFECTS =====
+    let wallet = ctx.accounts.user_profile.owner;
+    let verification = &mut ctx.accounts.verification;
+    verification.wallet = wallet;
+    verification.attester = authority;
+    verification.kind = kind;
+    verification.issued_at = clock.unix_timestamp;
+    verification.expires_at = expires_at;
+    verification.revoked = false;
+    verification.revoked_by = Pubkey::default();
+    verification.admin_revoked = false;
+    verification.bump = ctx.bumps.verification;
+    verification.version = ACCOUNT_VERSION;
+
+    let attester = &mut ctx.accounts.attester;
+    attester.issued_count = attester
+        .issued_count
+        .checked_add(1)
+        .ok_or(SocialFiError::ArithmeticOverflow)?;
+
+    emit!(VerificationIssued {
+        wallet,
+        attester: authority,
+        kind,
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ==================== Revoke Verification ====================
+
+#[derive(Accounts)]
+pub struct RevokeVerification<'info> {
+    #[account(
+        mut,
+        seeds = [VERIFICATION_SEED, verification.wallet.as_ref()],
+        bump = verification.bump,
+        constraint = !verification.revoked @ SocialFiError::VerificationAlreadyRevoked
+    )]
+    pub verification: Account<'info, Verification>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The issuing attester or the platform admin
+    #[account(
+        constraint = signer.key() == verification.attester
+            || signer.key() == platform_config.admin @ SocialFiError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+}
+
+/// Revoke a verification. The record is kept so revocations stay visible on-chain,
+/// and an admin revocation blocks any later re-issue for the wallet.
+pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
+    let clock = Clock::get()?;
+    let signer = ctx.accounts.signer.key();
+    let verification = &mut ctx.accounts.verification;
+    verification.revoked = true;
+    verification.revoked_by = signer;
+    // Recorded explicitly so an admin who is also the issuing attester still blocks re-issue
+    verification.admin_revoked = signer == ctx.accounts.platform_config.admin;
+
+    emit!(VerificationRevoked {
+        wallet: verification.wallet,
+        attester: verification.attester,
+        signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -7,8 +7,11 @@ pub mod instructions;
 pub mod errors;
 pub mod events;
 pub mod constants;
+pub mod username;
+pub mod identity;
 
 use instructions::*;
+use state::{BatchTip, FeeSchedule, MultisigAccountMeta};
 
 #[program]
 pub mod social_fi_contract {
@@ -20,7 +23,7 @@ pub mod social_fi_contract {
         instructions::platform::initialize_platform(ctx, fee_collector)
     }
     
-    pub fn pause_platform(ctx: Context<UpdatePlatform>) -> Result<()> {
+    pub fn pause_platform(ctx: Context<PausePlatform>) -> Result<()> {
         instructions::platform::pause_platform(ctx)
     }
     
@@ -28,8 +31,12 @@ pub mod social_fi_contract {
         instructions::platform::unpause_platform(ctx)
     }
     
-    pub fn update_admin(ctx: Context<UpdatePlatform>, new_admin: Pubkey) -> Result<()> {
-        instructions::platform::update_admin(ctx, new_admin)
+    pub fn pause_modules(ctx: Context<UpdatePlatform>, modules: u8) -> Result<()> {
+        instructions::platform::pause_modules(ctx, modules)
+    }
+    
+    pub fn unpause_modules(ctx: Context<UpdatePlatform>, modules: u8) -> Result<()> {
+        instructions::platform::unpause_modules(ctx, modules)
     }
     
     pub fn update_fee_collector(ctx: Context<UpdatePlatform>, new_fee_collector: Pubkey) -> Result<()> {
@@ -39,16 +46,225 @@ pub mod social_fi_contract {
     pub fn update_min_liquidity(ctx: Context<UpdatePlatform>, new_min_liquidity_bps: u64) -> Result<()> {
         instructions::platform::update_min_liquidity(ctx, new_min_liquidity_bps)
     }
+    
+    pub fn update_fee_schedule(ctx: Context<UpdatePlatform>, fee_schedule: FeeSchedule) -> Result<()> {
+        instructions::platform::update_fee_schedule(ctx, fee_schedule)
+    }
+    
+    pub fn update_referral_share(ctx: Context<UpdatePlatform>, new_referral_share_bps: u64) -> Result<()> {
+        instructions::platform::update_referral_share(ctx, new_referral_share_bps)
+    }
+    
+    pub fn set_guardian(ctx: Context<UpdatePlatform>, new_guardian: Pubkey) -> Result<()> {
+        instructions::platform::set_guardian(ctx, new_guardian)
+    }
+    
+    pub fn set_identity_oracle(ctx: Context<UpdatePlatform>, new_oracle: Pubkey) -> Result<()> {
+        instructions::platform::set_identity_oracle(ctx, new_oracle)
+    }
+    
+    pub fn initialize_protocol_params(ctx: Context<InitializeProtocolParams>) -> Result<()> {
+        instructions::platform::initialize_protocol_params(ctx)
+    }
+    
+    pub fn update_base_price(ctx: Context<UpdateProtocolParams>, new_base_price: u64) -> Result<()> {
+        instructions::platform::update_base_price(ctx, new_base_price)
+    }
+    
+    pub fn update_min_offer_amount(ctx: Context<UpdateProtocolParams>, new_min_offer_amount: u64) -> Result<()> {
+        instructions::platform::update_min_offer_amount(ctx, new_min_offer_amount)
+    }
+    
+    pub fn update_voting_params(
+        ctx: Context<UpdateProtocolParams>,
+        new_voting_period: i64,
+        new_min_voting_power: u64,
+    ) -> Result<()> {
+        instructions::platform::update_voting_params(ctx, new_voting_period, new_min_voting_power)
+    }
+    
+    pub fn update_staking_rates(
+        ctx: Context<UpdateProtocolParams>,
+        new_lock_multipliers_bps: [u64; 5],
+        new_apy_bps: [u64; 5],
+    ) -> Result<()> {
+        instructions::platform::update_staking_rates(ctx, new_lock_multipliers_bps, new_apy_bps)
+    }
+    
+    pub fn initialize_platform_stats(ctx: Context<InitializePlatformStats>) -> Result<()> {
+        instructions::platform::initialize_platform_stats(ctx)
+    }
+    
+    pub fn propose_admin(ctx: Context<UpdatePlatform>, new_admin: Pubkey) -> Result<()> {
+        instructions::platform::propose_admin(ctx, new_admin)
+    }
+    
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::platform::accept_admin(ctx)
+    }
+    
+    pub fn cancel_admin_transfer(ctx: Context<UpdatePlatform>) -> Result<()> {
+        instructions::platform::cancel_admin_transfer(ctx)
+    }
+
+    // ==================== Multisig Administration ====================
+
+    pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::multisig::create_multisig(ctx, signers, threshold)
+    }
+
+    pub fn change_multisig_signers(
+        ctx: Context<ChangeMultisigSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::multisig::change_multisig_signers(ctx, signers, threshold)
+    }
+
+    pub fn propose_multisig_transaction(
+        ctx: Context<ProposeMultisigTransaction>,
+        accounts: Vec<MultisigAccountMeta>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::multisig::propose_multisig_transaction(ctx, accounts, data)
+    }
+
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+        instructions::multisig::approve_multisig_transaction(ctx)
+    }
+
+    pub fn execute_multisig_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigTransaction<'info>>,
+    ) -> Result<()> {
+        instructions::multisig::execute_multisig_transaction(ctx)
+    }
+
+    // ==================== Blocklist ====================
+    
+    pub fn add_to_blocklist(ctx: Context<AddToBlocklist>, wallet: Pubkey) -> Result<()> {
+        instructions::blocklist::add_to_blocklist(ctx, wallet)
+    }
+    
+    pub fn remove_from_blocklist(ctx: Context<RemoveFromBlocklist>) -> Result<()> {
+        instructions::blocklist::remove_from_blocklist(ctx)
+    }
+    
+    pub fn add_tip_mint(ctx: Context<AddTipMint>) -> Result<()> {
+        instructions::token_tip::add_tip_mint(ctx)
+    }
+    
+    pub fn remove_tip_mint(ctx: Context<RemoveTipMint>) -> Result<()> {
+        instructions::token_tip::remove_tip_mint(ctx)
+    }
+
+    // ==================== Verification ====================
+    
+    pub fn add_attester(ctx: Context<AddAttester>, authority: Pubkey) -> Result<()> {
+        instructions::verification::add_attester(ctx, authority)
+    }
+    
+    pub fn remove_attester(ctx: Context<RemoveAttester>) -> Result<()> {
+        instructions::verification::remove_attester(ctx)
+    }
+    
+    pub fn issue_verification(ctx: Context<IssueVerification>, kind: u8, expires_at: i64) -> Result<()> {
+        instructions::verification::issue_verification(ctx, kind, expires_at)
+    }
+    
+    pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
+        instructions::verification::revoke_verification(ctx)
+    }
+    
+    pub fn link_identity(ctx: Context<LinkIdentity>, kind: u8, handle: String, expires_at: i64) -> Result<()> {
+        instructions::identity::link_identity(ctx, kind, handle, expires_at)
+    }
+    
+    pub fn unlink_identity(ctx: Context<UnlinkIdentity>) -> Result<()> {
+        instructions::identity::unlink_identity(ctx)
+    }
+
+    // ==================== Account Migration ====================
+    
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_account(ctx)
+    }
+    
+    pub fn migrate_platform_config(ctx: Context<MigratePlatformConfig>) -> Result<()> {
+        instructions::migration::migrate_platform_config(ctx)
+    }
 
     // ==================== User & Tipping ====================
     
-    pub fn initialize_user(ctx: Context<InitializeUser>, username: String) -> Result<()> {
-        instructions::user::initialize_user(ctx, username)
+    pub fn initialize_user(
+        ctx: Context<InitializeUser>,
+        username: String,
+        referral_code: Option<String>,
+    ) -> Result<()> {
+        instructions::user::initialize_user(ctx, username, referral_code)
+    }
+
+    pub fn register_referral_code(ctx: Context<RegisterReferralCode>) -> Result<()> {
+        instructions::user::register_referral_code(ctx)
+    }
+
+    pub fn change_username(ctx: Context<ChangeUsername>, new_username: String) -> Result<()> {
+        instructions::user::change_username(ctx, new_username)
+    }
+    
+    pub fn register_username(ctx: Context<RegisterUsername>) -> Result<()> {
+        instructions::user::register_username(ctx)
+    }
+    
+    pub fn close_user_profile(ctx: Context<CloseUserProfile>) -> Result<()> {
+        instructions::user::close_user_profile(ctx)
+    }
+    
+    pub fn update_profile(
+        ctx: Context<UpdateProfile>,
+        display_name: Option<String>,
+        bio: Option<String>,
+        avatar_uri: Option<String>,
+        banner_uri: Option<String>,
+        website: Option<String>,
+    ) -> Result<()> {
+        instructions::user::update_profile(ctx, display_name, bio, avatar_uri, banner_uri, website)
+    }
+    
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        instructions::referral::claim_referral_rewards(ctx)
     }
 
     pub fn send_tip(ctx: Context<SendTip>, amount: u64) -> Result<()> {
         instructions::user::send_tip(ctx, amount)
     }
+    
+    pub fn send_tips_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendTipsBatch<'info>>,
+        tips: Vec<BatchTip>,
+    ) -> Result<()> {
+        instructions::user::send_tips_batch(ctx, tips)
+    }
+    
+    pub fn send_token_tip(ctx: Context<SendTokenTip>, amount: u64) -> Result<()> {
+        instructions::token_tip::send_token_tip(ctx, amount)
+    }
+    
+    pub fn create_tip_stream(
+        ctx: Context<CreateTipStream>,
+        amount_per_period: u64,
+        period: i64,
+        deposit: u64,
+    ) -> Result<()> {
+        instructions::tip_stream::create_tip_stream(ctx, amount_per_period, period, deposit)
+    }
+    
+    pub fn crank_tip_stream(ctx: Context<CrankTipStream>) -> Result<()> {
+        instructions::tip_stream::crank_tip_stream(ctx)
+    }
+    
+    pub fn cancel_tip_stream(ctx: Context<CancelTipStream>) -> Result<()> {
+        instructions::tip_stream::cancel_tip_stream(ctx)
+    }
 
     // ==================== Creator Shares (Bonding Curve) ====================
     
@@ -166,6 +382,18 @@ pub mod social_fi_contract {
     pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
         instructions::marketplace::cancel_listing(ctx)
     }
+    
+    pub fn set_primary_username(ctx: Context<SetPrimaryUsername>) -> Result<()> {
+        instructions::marketplace::set_primary_username(ctx)
+    }
+    
+    pub fn clear_primary_username(ctx: Context<ClearPrimaryUsername>) -> Result<()> {
+        instructions::marketplace::clear_primary_username(ctx)
+    }
+    
+    pub fn sync_username_verification(ctx: Context<SyncUsernameVerification>) -> Result<()> {
+        instructions::marketplace::sync_username_verification(ctx)
+    }
 
     // ==================== Posts ====================
 
@@ -177,8 +405,34 @@ pub mod social_fi_contract {
         instructions::post::mint_post(ctx, title, nft_metadata_uri)
     }
 
+    pub fn tip_post(ctx: Context<TipPost>, amount: u64, message: String) -> Result<()> {
+        instructions::post::tip_post(ctx, amount, message)
+    }
+
     // ==================== Social Interactions ====================
 
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        session_signer: Pubkey,
+        scope: u8,
+        duration: i64,
+        allowance: u64,
+    ) -> Result<()> {
+        instructions::session::create_session_key(ctx, session_signer, scope, duration, allowance)
+    }
+
+    pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+        instructions::session::revoke_session_key(ctx)
+    }
+
+    pub fn block_user(ctx: Context<BlockUser>) -> Result<()> {
+        instructions::social::block_user(ctx)
+    }
+
+    pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+        instructions::social::unblock_user(ctx)
+    }
+
     pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
         instructions::social::follow_user(ctx)
     }
@@ -1,6 +1,63 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 
+// ==================== Account Versioning ====================
+//
+// Every account ends with `version` followed by zeroed `reserved` padding.
+// Because both trail the pre-versioning (v0) layout, a v0 account is a prefix
+// of the current layout: `migrate_account` grows it with `realloc`, the new
+// bytes read back as version 0 and empty padding, and the version is bumped.
+// New fields should be carved out of `reserved` (placed before it) so existing
+// accounts keep deserializing without another migration.
+
+/// Accounts that `migrate_account` can upgrade in place
+pub trait Versioned {
+    const LEN: usize;
+
+    fn version(&self) -> u8;
+    fn set_version(&mut self, version: u8);
+}
+
+macro_rules! impl_versioned {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl Versioned for $account {
+                const LEN: usize = <$account>::LEN;
+
+                fn version(&self) -> u8 {
+                    self.version
+                }
+
+                fn set_version(&mut self, version: u8) {
+                    self.version = version;
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(
+    UserProfile,
+    CreatorPool,
+    ShareHolding,
+    SubscriptionTier,
+    Subscription,
+    Group,
+    GroupMember,
+    StakePosition,
+    Proposal,
+    Vote,
+    PlatformConfig,
+    UsernameNFT,
+    Listing,
+    Offer,
+    Post,
+    Follow,
+    Like,
+    Repost,
+    Comment,
+);
+
 // ==================== User Profile ====================
 
 #[account]
@@ -17,10 +74,92 @@ pub struct UserProfile {
     pub referrals_count: u64,       // 8
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub display_name: String,       // 4 + variable
+    pub bio: String,                // 4 + variable
+    pub avatar_uri: String,         // 4 + variable
+    pub banner_uri: String,         // 4 + variable
+    pub website: String,            // 4 + variable
+    pub primary_username_mint: Pubkey, // 32 (default when no username NFT is bound)
+    pub open_listings: u32,         // 4
+    pub active_tip_streams: u32,    // 4 (open streams it sends or receives)
+    pub session_keys: u32,          // 4 (unrevoked session keys)
+    pub reserved: [u8; 64],         // 64
 }
 
 impl UserProfile {
-    pub const LEN: usize = 8 + 32 + 24 + 8 + 8 + 8 + 8 + 8 + 14 + 33 + 8 + 8 + 1;
+    /// Size with every rich profile field empty; `update_profile` grows the account beyond this
+    pub const LEN: usize = 8 + 32 + 24 + 8 + 8 + 8 + 8 + 8 + 14 + 33 + 8 + 8 + 1 + 1 + 4 * 5 + 32 + 4 + 4 + 4 + 64;
+
+    /// Account size needed to hold the current rich profile fields
+    pub fn space(&self) -> usize {
+        Self::LEN
+            + self.display_name.len()
+            + self.bio.len()
+            + self.avatar_uri.len()
+            + self.banner_uri.len()
+            + self.website.len()
+    }
+}
+
+/// Maps a referral code to the profile that owns it
+#[account]
+pub struct ReferralCode {
+    pub code: String,               // 4 + 10 = 14
+    pub owner: Pubkey,              // 32
+    pub profile: Pubkey,            // 32
+    pub created_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl ReferralCode {
+    pub const LEN: usize = 8 + 14 + 32 + 32 + 8 + 1 + 1 + 64;
+
+    /// Whether the code is free to be claimed by `owner`: it is new, or left by
+    /// `owner`'s earlier profile. Codes are a short wallet prefix, so another
+    /// wallet can derive the same one.
+    pub fn is_available_to(&self, owner: &Pubkey) -> bool {
+        self.owner == Pubkey::default() || self.owner == *owner
+    }
+}
+
+/// Referral earnings held in this PDA until the referrer claims them
+#[account]
+pub struct ReferralRewards {
+    pub referrer: Pubkey,           // 32
+    pub unclaimed: u64,             // 8 (lamports held above rent)
+    pub total_earned: u64,          // 8
+    pub total_claimed: u64,         // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl ReferralRewards {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 1 + 1 + 64;
+}
+
+/// Claims a normalized username for the profile that holds it
+#[account]
+pub struct UsernameRegistry {
+    pub username: String,           // 4 + 20 = 24 (as displayed on the profile)
+    pub profile: Pubkey,            // 32
+    pub owner: Pubkey,              // 32
+    pub claimed_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl UsernameRegistry {
+    pub const LEN: usize = 8 + 24 + 32 + 32 + 8 + 1 + 1 + 64;
+
+    /// Whether the entry is free to be claimed by `profile`
+    pub fn is_available_to(&self, profile: &Pubkey) -> bool {
+        self.profile == Pubkey::default() || self.profile == *profile
+    }
 }
 
 // ==================== Creator Shares ====================
@@ -34,10 +173,18 @@ pub struct CreatorPool {
     pub total_volume: u64,          // 8
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub sell_fee_bps: Option<u64>,  // 1 + 8 = 9 (snapshotted at creation; None for legacy pools)
+    pub reserved: [u8; 55],         // 55
 }
 
 impl CreatorPool {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 9 + 55;
+
+    /// Sell fee in basis points: the pool's snapshot, or the live schedule for legacy pools
+    pub fn sell_fee_bps(&self, fee_schedule: &FeeSchedule) -> u64 {
+        self.sell_fee_bps.unwrap_or(fee_schedule.share_sell_bps)
+    }
 
     pub fn calculate_price(&self, supply: u64) -> Result<u64> {
         // Enforce maximum supply to prevent overflow
@@ -124,18 +271,8 @@ impl CreatorPool {
         }
         
         // Convert to u64 for fee calculation
-        let total_return_u64 = total_return.min(u64::MAX as u128) as u64;
-        
-        // Apply 10% sell fee
-        let fee = total_return_u64
-            .checked_mul(SELL_FEE_BPS)
-            .ok_or(error!(crate::errors::SocialFiError::ArithmeticOverflow))?
-            .checked_div(BPS_DENOMINATOR)
-            .ok_or(error!(crate::errors::SocialFiError::ArithmeticUnderflow))?;
-        
-        total_return_u64
-            .checked_sub(fee)
-            .ok_or(error!(crate::errors::SocialFiError::ArithmeticUnderflow))
+        // Gross return before platform fees (fees are applied by the caller)
+        Ok(total_return.min(u64::MAX as u128) as u64)
     }
 }
 
@@ -147,10 +284,12 @@ pub struct ShareHolding {
     pub average_price: u64,         // 8
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl ShareHolding {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 64;
 }
 
 // ==================== Subscriptions ====================
@@ -166,10 +305,12 @@ pub struct SubscriptionTier {
     pub subscriber_count: u64,      // 8
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl SubscriptionTier {
-    pub const LEN: usize = 8 + 32 + 8 + 24 + 104 + 8 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 + 24 + 104 + 8 + 8 + 8 + 8 + 1 + 1 + 64;
 }
 
 #[account]
@@ -183,10 +324,12 @@ pub struct Subscription {
     pub auto_renew: bool,           // 1
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Subscription {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 8 + 1 + 1 + 64;
 
     pub fn is_active(&self, current_time: i64) -> bool {
         self.status == 0 && current_time < self.end_date
@@ -210,10 +353,12 @@ pub struct Group {
     pub post_count: u64,            // 8
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Group {
-    pub const LEN: usize = 8 + 32 + 54 + 204 + 32 + 1 + 1 + 9 + 33 + 33 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 54 + 204 + 32 + 1 + 1 + 9 + 33 + 33 + 8 + 8 + 8 + 1 + 1 + 64;
 }
 
 #[account]
@@ -224,10 +369,12 @@ pub struct GroupMember {
     pub joined_at: i64,             // 8
     pub banned: bool,               // 1
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl GroupMember {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1 + 1 + 1 + 64;
 
     pub fn can_manage_members(&self) -> bool {
         self.role <= 1 // owner or admin
@@ -250,20 +397,16 @@ pub struct StakePosition {
     pub rewards: u64,               // 8
     pub voting_power: u64,          // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub apy_bps: Option<u64>,       // 1 + 8 = 9 (snapshotted at stake time; None for legacy positions)
+    pub reserved: [u8; 55],         // 55
 }
 
 impl StakePosition {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
-
-    pub fn calculate_voting_power(amount: u64, lock_period: u64) -> Result<u64> {
-        let multiplier = match lock_period {
-            LOCK_0_DAYS => MULTIPLIER_0_DAYS,
-            LOCK_30_DAYS => MULTIPLIER_30_DAYS,
-            LOCK_90_DAYS => MULTIPLIER_90_DAYS,
-            LOCK_180_DAYS => MULTIPLIER_180_DAYS,
-            LOCK_365_DAYS => MULTIPLIER_365_DAYS,
-            _ => return Err(error!(crate::errors::SocialFiError::InvalidLockPeriod)),
-        };
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 9 + 55;
+
+    pub fn calculate_voting_power(amount: u64, lock_period: u64, params: &ProtocolParams) -> Result<u64> {
+        let multiplier = params.lock_multipliers_bps[ProtocolParams::lock_tier(lock_period)?];
 
         amount
             .checked_mul(multiplier)
@@ -272,18 +415,17 @@ impl StakePosition {
             .ok_or(error!(crate::errors::SocialFiError::ArithmeticUnderflow))
     }
 
-    pub fn calculate_rewards(&self, current_time: i64) -> Result<u64> {
+    pub fn calculate_rewards(&self, current_time: i64, params: &ProtocolParams) -> Result<u64> {
         let time_staked = current_time
             .checked_sub(self.staked_at)
             .ok_or(error!(crate::errors::SocialFiError::InvalidTimestamp))?;
         
-        let apy = match self.lock_period {
-            LOCK_0_DAYS => APY_0_DAYS,
-            LOCK_30_DAYS => APY_30_DAYS,
-            LOCK_90_DAYS => APY_90_DAYS,
-            LOCK_180_DAYS => APY_180_DAYS,
-            LOCK_365_DAYS => APY_365_DAYS,
-            _ => APY_0_DAYS,
+        // Positions staked before the snapshot existed fall back to the live rate
+        let apy = match self.apy_bps {
+            Some(apy) => apy,
+            None => ProtocolParams::lock_tier(self.lock_period)
+                .map(|tier| params.apy_bps[tier])
+                .unwrap_or(params.apy_bps[0]),
         };
 
         // rewards = amount * apy * time_staked / SECONDS_PER_YEAR / BPS_DENOMINATOR
@@ -322,10 +464,12 @@ pub struct Proposal {
     pub quorum_required: u64,       // 8
     pub executed_at: Option<i64>,   // 1 + 8 = 9
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Proposal {
-    pub const LEN: usize = 8 + 32 + 32 + 104 + 504 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 9 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 104 + 504 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 9 + 1 + 1 + 64;
 
     pub fn is_active(&self, current_time: i64) -> bool {
         self.status == 0 && current_time < self.voting_ends_at
@@ -351,10 +495,12 @@ pub struct Vote {
     pub voting_power: u64,          // 8
     pub voted_at: i64,              // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Vote {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1 + 1 + 64;
 }
 
 // ==================== Platform Config ====================
@@ -363,13 +509,398 @@ impl Vote {
 pub struct PlatformConfig {
     pub admin: Pubkey,              // 32
     pub fee_collector: Pubkey,      // 32
-    pub paused: bool,               // 1
+    pub paused_modules: u8,         // 1 (bitmask of PAUSE_* flags)
     pub min_liquidity_bps: u64,     // 8 (basis points, e.g., 1000 = 10%)
+    pub pending_admin: Option<Pubkey>, // 1 + 32 = 33 (proposed admin awaiting acceptance)
+    pub admin_transfer_eta: i64,    // 8 (earliest time pending_admin may accept)
+    pub fee_schedule: FeeSchedule,  // 48
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub guardian: Pubkey,           // 32 (emergency pause key; default = none)
+    pub referral_share_bps: u64,    // 8 (share of platform fees paid to the payer's referrer)
+    pub identity_oracle: Pubkey,    // 32 (attestation service key for linked identities; default = none)
+    pub reserved: [u8; 56],         // 56
 }
 
 impl PlatformConfig {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 33 + 8 + FeeSchedule::LEN + 1 + 1 + 32 + 8 + 32 + 56;
+
+    pub fn calculate_fee(amount: u64, fee_bps: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(error!(crate::errors::SocialFiError::ArithmeticOverflow))?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(error!(crate::errors::SocialFiError::ArithmeticUnderflow))?;
+        Ok(fee as u64)
+    }
+
+    pub fn is_paused(&self, module: u8) -> bool {
+        self.paused_modules & module != 0
+    }
+
+    /// The admin, or the guardian when one is set
+    pub fn can_pause(&self, authority: &Pubkey) -> bool {
+        *authority == self.admin || (self.guardian != Pubkey::default() && *authority == self.guardian)
+    }
+}
+
+/// Platform fee per revenue stream, in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSchedule {
+    pub share_buy_bps: u64,         // 8
+    pub share_sell_bps: u64,        // 8
+    pub tip_bps: u64,               // 8
+    pub subscription_bps: u64,      // 8
+    pub group_entry_bps: u64,       // 8
+    pub marketplace_bps: u64,       // 8
+}
+
+impl FeeSchedule {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8;
+
+    pub fn default_schedule() -> Self {
+        Self {
+            share_buy_bps: DEFAULT_SHARE_BUY_FEE_BPS,
+            share_sell_bps: SELL_FEE_BPS,
+            tip_bps: DEFAULT_TIP_FEE_BPS,
+            subscription_bps: DEFAULT_SUBSCRIPTION_FEE_BPS,
+            group_entry_bps: DEFAULT_GROUP_ENTRY_FEE_BPS,
+            marketplace_bps: DEFAULT_MARKETPLACE_FEE_BPS,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let rates = [
+            self.share_buy_bps,
+            self.share_sell_bps,
+            self.tip_bps,
+            self.subscription_bps,
+            self.group_entry_bps,
+            self.marketplace_bps,
+        ];
+        require!(
+            rates.iter().all(|bps| *bps <= MAX_PLATFORM_FEE_BPS),
+            crate::errors::SocialFiError::FeeTooHigh
+        );
+        Ok(())
+    }
+}
+
+// ==================== Protocol Params ====================
+
+/// Economic parameters the admin can tune without a program upgrade.
+/// Pools copy `base_price` at creation, so changes only affect new pools.
+#[account]
+pub struct ProtocolParams {
+    pub base_price: u64,            // 8 (bonding curve base price for new pools)
+    pub min_offer_amount: u64,      // 8
+    pub voting_period: i64,         // 8 (seconds)
+    pub min_voting_power: u64,      // 8 (required to create a proposal)
+    pub lock_multipliers_bps: [u64; LOCK_TIER_COUNT], // 8 * 5 = 40 (indexed like LOCK_PERIODS)
+    pub apy_bps: [u64; LOCK_TIER_COUNT], // 8 * 5 = 40 (indexed like LOCK_PERIODS)
+    pub updated_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl ProtocolParams {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 * LOCK_TIER_COUNT + 8 * LOCK_TIER_COUNT + 8 + 1 + 1 + 64;
+
+    pub const DEFAULT_LOCK_MULTIPLIERS_BPS: [u64; LOCK_TIER_COUNT] = [
+        MULTIPLIER_0_DAYS,
+        MULTIPLIER_30_DAYS,
+        MULTIPLIER_90_DAYS,
+        MULTIPLIER_180_DAYS,
+        MULTIPLIER_365_DAYS,
+    ];
+
+    pub const DEFAULT_APY_BPS: [u64; LOCK_TIER_COUNT] = [
+        APY_0_DAYS,
+        APY_30_DAYS,
+        APY_90_DAYS,
+        APY_180_DAYS,
+        APY_365_DAYS,
+    ];
+
+    /// Index into the per-lock tables for a supported lock period (in days)
+    pub fn lock_tier(lock_period: u64) -> Result<usize> {
+        LOCK_PERIODS
+            .iter()
+            .position(|period| *period == lock_period)
+            .ok_or(error!(crate::errors::SocialFiError::InvalidLockPeriod))
+    }
+
+    pub fn validate_base_price(base_price: u64) -> Result<()> {
+        require!(
+            (MIN_BASE_PRICE..=MAX_BASE_PRICE).contains(&base_price),
+            crate::errors::SocialFiError::ProtocolParamOutOfRange
+        );
+        Ok(())
+    }
+
+    pub fn validate_min_offer_amount(min_offer_amount: u64) -> Result<()> {
+        require!(
+            (1..=MAX_MIN_OFFER_AMOUNT).contains(&min_offer_amount),
+            crate::errors::SocialFiError::ProtocolParamOutOfRange
+        );
+        Ok(())
+    }
+
+    pub fn validate_voting_params(voting_period: i64, min_voting_power: u64) -> Result<()> {
+        require!(
+            (MIN_VOTING_PERIOD..=MAX_VOTING_PERIOD).contains(&voting_period)
+                && (1..=MAX_MIN_VOTING_POWER).contains(&min_voting_power),
+            crate::errors::SocialFiError::ProtocolParamOutOfRange
+        );
+        Ok(())
+    }
+
+    /// Longer locks may never earn a lower multiplier or APY than shorter ones
+    pub fn validate_staking_rates(
+        lock_multipliers_bps: &[u64; LOCK_TIER_COUNT],
+        apy_bps: &[u64; LOCK_TIER_COUNT],
+    ) -> Result<()> {
+        require!(
+            lock_multipliers_bps
+                .iter()
+                .all(|bps| (BPS_DENOMINATOR..=MAX_LOCK_MULTIPLIER_BPS).contains(bps))
+                && apy_bps.iter().all(|bps| *bps <= MAX_APY_BPS),
+            crate::errors::SocialFiError::ProtocolParamOutOfRange
+        );
+        require!(
+            lock_multipliers_bps.windows(2).all(|pair| pair[0] <= pair[1])
+                && apy_bps.windows(2).all(|pair| pair[0] <= pair[1]),
+            crate::errors::SocialFiError::ProtocolParamOutOfRange
+        );
+        Ok(())
+    }
+}
+
+// ==================== Platform Stats ====================
+
+/// Platform-wide counters maintained by the instruction handlers
+#[account]
+pub struct PlatformStats {
+    pub total_users: u64,               // 8
+    pub total_pools: u64,               // 8
+    pub total_share_volume: u64,        // 8 (lamports, buys + sells)
+    pub total_tip_volume: u64,          // 8 (lamports, gross)
+    pub total_subscriptions: u64,       // 8
+    pub total_groups: u64,              // 8
+    pub total_posts: u64,               // 8
+    pub total_staked: u64,              // 8 (currently staked)
+    pub total_marketplace_volume: u64,  // 8 (lamports, listings + offers)
+    pub bump: u8,                       // 1
+    pub version: u8,                    // 1
+    pub reserved: [u8; 128],            // 128
+}
+
+impl PlatformStats {
+    pub const LEN: usize = 8 + 8 * 9 + 1 + 1 + 128;
+}
+
+// ==================== Blocklist ====================
+
+/// Existence of this PDA bars `wallet` from value transfers
+#[account]
+pub struct Blocked {
+    pub wallet: Pubkey,             // 32
+    pub blocked_by: Pubkey,         // 32 (admin that added the entry)
+    pub blocked_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl Blocked {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 64;
+}
+
+// ==================== Verification ====================
+
+/// Existence of this PDA lets `authority` issue and revoke verifications
+#[account]
+pub struct Attester {
+    pub authority: Pubkey,          // 32
+    pub added_by: Pubkey,           // 32 (admin that approved the attester)
+    pub added_at: i64,              // 8
+    pub issued_count: u64,          // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl Attester {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 64;
+}
+
+/// Attestation that `wallet` is verified, one per wallet. Revoked records are kept for history.
+#[account]
+pub struct Verification {
+    pub wallet: Pubkey,             // 32
+    pub attester: Pubkey,           // 32
+    pub kind: u8,                   // 1 (VERIFICATION_KIND_*)
+    pub issued_at: i64,             // 8
+    pub expires_at: i64,            // 8
+    pub revoked: bool,              // 1
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub revoked_by: Pubkey,         // 32 (default while active or for legacy revocations)
+    pub admin_revoked: bool,        // 1 (revoked by the platform admin; blocks re-issue)
+    pub reserved: [u8; 31],         // 31
+}
+
+impl Verification {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1 + 1 + 1 + 32 + 1 + 31;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        !self.revoked && now < self.expires_at
+    }
+}
+
+/// External identity (social handle, domain) the identity oracle attested for `wallet`, one per kind
+#[account]
+pub struct LinkedIdentity {
+    pub wallet: Pubkey,             // 32
+    pub profile: Pubkey,            // 32
+    pub kind: u8,                   // 1 (IDENTITY_KIND_*)
+    pub handle: String,             // 4 + 64 = 68
+    pub oracle: Pubkey,             // 32 (oracle key that signed the proof)
+    pub linked_at: i64,             // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl LinkedIdentity {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + (4 + MAX_IDENTITY_HANDLE_LENGTH) + 32 + 8 + 1 + 1 + 64;
+}
+
+// ==================== Tips ====================
+
+/// One recipient of `send_tips_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchTip {
+    pub recipient: Pubkey,          // 32
+    pub amount: u64,                // 8
+}
+
+/// Recurring tip from `sender` to `recipient`, paid out of lamports escrowed in this PDA
+#[account]
+pub struct TipStream {
+    pub sender: Pubkey,             // 32
+    pub recipient: Pubkey,          // 32
+    pub amount_per_period: u64,     // 8 (lamports)
+    pub period: i64,                // 8 (seconds)
+    pub balance: u64,               // 8 (escrowed lamports above rent)
+    pub next_payment_at: i64,       // 8
+    pub total_paid: u64,            // 8
+    pub created_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl TipStream {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 64;
+
+    /// Periods due at `now` that the escrow can cover
+    pub fn payable_periods(&self, now: i64) -> Result<u64> {
+        if now < self.next_payment_at {
+            return Ok(0);
+        }
+        let elapsed = now
+            .checked_sub(self.next_payment_at)
+            .ok_or(error!(crate::errors::SocialFiError::ArithmeticUnderflow))?;
+        let due = (elapsed / self.period) as u64 + 1;
+        let affordable = self.balance / self.amount_per_period;
+        Ok(due.min(affordable))
+    }
+}
+
+/// Existence of this PDA allows `mint` for token tips; also tracks per-mint totals
+#[account]
+pub struct TipMint {
+    pub mint: Pubkey,               // 32
+    pub total_tipped: u64,          // 8 (in base units of the mint, before fees)
+    pub tip_count: u64,             // 8
+    pub added_by: Pubkey,           // 32
+    pub added_at: i64,              // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl TipMint {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 32 + 8 + 1 + 1 + 64;
+}
+
+// ==================== Multisig ====================
+
+/// M-of-N signer set. Its vault PDA `[MULTISIG_VAULT_SEED, multisig]` is the
+/// authority that gets set as `PlatformConfig.admin`; approved transactions are
+/// executed as CPIs into this program signed by that vault.
+#[account]
+pub struct Multisig {
+    pub creator: Pubkey,            // 32
+    pub signers: Vec<Pubkey>,       // 4 + 32 * 10 = 324
+    pub threshold: u8,              // 1
+    pub transaction_count: u64,     // 8
+    pub vault_bump: u8,             // 1
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub signer_set_seqno: u32,      // 4 (bumped on every signer change; voids pending approvals)
+    pub reserved: [u8; 60],         // 60
+}
+
+impl Multisig {
+    pub const LEN: usize = 8 + 32 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1 + 8 + 1 + 1 + 1 + 4 + 60;
+
+    pub fn signer_index(&self, key: &Pubkey) -> Option<usize> {
+        self.signers.iter().position(|signer| signer == key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MultisigAccountMeta {
+    pub pubkey: Pubkey,             // 32
+    pub is_signer: bool,            // 1
+    pub is_writable: bool,          // 1
+}
+
+impl MultisigAccountMeta {
+    pub const LEN: usize = 32 + 1 + 1;
+}
+
+#[account]
+pub struct MultisigTransaction {
+    pub multisig: Pubkey,           // 32
+    pub proposer: Pubkey,           // 32
+    pub index: u64,                 // 8
+    pub accounts: Vec<MultisigAccountMeta>, // 4 + 34 * n
+    pub data: Vec<u8>,              // 4 + n
+    pub approvals: u16,             // 2 (bitmask over Multisig.signers indices)
+    pub executed: bool,             // 1
+    pub created_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub signer_set_seqno: u32,      // 4 (Multisig.signer_set_seqno when proposed)
+    pub reserved: [u8; 60],         // 60
+}
+
+impl MultisigTransaction {
+    pub fn space(accounts_len: usize, data_len: usize) -> usize {
+        8 + 32 + 32 + 8 + (4 + MultisigAccountMeta::LEN * accounts_len) + (4 + data_len) + 2 + 1 + 8 + 1 + 1 + 4 + 60
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    pub fn has_approved(&self, signer_index: usize) -> bool {
+        self.approvals & (1 << signer_index) != 0
+    }
 }
 
 // ==================== Marketplace ====================
@@ -383,10 +914,37 @@ pub struct UsernameNFT {
     pub verified: bool,             // 1
     pub minted_at: i64,             // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl UsernameNFT {
-    pub const LEN: usize = 8 + 32 + 24 + 32 + 204 + 1 + 8 + 1; // 310 bytes
+    pub const LEN: usize = 8 + 32 + 24 + 32 + 204 + 1 + 8 + 1 + 1 + 64; // 375 bytes
+}
+
+/// Resolves a username NFT handle to the profile that set it as primary
+#[account]
+pub struct UsernameResolver {
+    pub username: String,           // 4 + 20 = 24
+    pub mint: Pubkey,               // 32
+    pub profile: Pubkey,            // 32
+    pub owner: Pubkey,              // 32 - NFT holder when the binding was made
+    pub bound_at: i64,              // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl UsernameResolver {
+    pub const LEN: usize = 8 + 24 + 32 + 32 + 32 + 8 + 1 + 1 + 64;
+
+    /// A binding only holds while the binder still owns the NFT and the profile still names it as primary.
+    /// `holder` is the owner of the token account holding the NFT, not the possibly stale `UsernameNFT.owner`.
+    pub fn is_bound(&self, profile: &UserProfile, holder: &Pubkey) -> bool {
+        self.owner == *holder
+            && profile.owner == *holder
+            && profile.primary_username_mint == self.mint
+    }
 }
 
 #[account]
@@ -398,10 +956,12 @@ pub struct Listing {
     pub listed_at: i64,             // 8
     pub expires_at: Option<i64>,    // 1 + 8 = 9
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Listing {
-    pub const LEN: usize = 8 + 32 + 24 + 8 + 1 + 8 + 9 + 1;
+    pub const LEN: usize = 8 + 32 + 24 + 8 + 1 + 8 + 9 + 1 + 1 + 64;
 }
 
 #[account]
@@ -412,10 +972,12 @@ pub struct Offer {
     pub created_at: i64,            // 8
     pub expires_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Offer {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 64;
 
     pub fn is_expired(&self, current_time: i64) -> bool {
         current_time >= self.expires_at
@@ -432,24 +994,68 @@ pub struct Post {
     pub mint: Option<Pubkey>,       // 1 + 32 = 33
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub tips_received: u64,         // 8 (lamports, net of platform fee)
+    pub author_verified: bool,      // 1 (author held an active verification at creation)
+    pub reserved: [u8; 55],         // 55
 }
 
 impl Post {
-    pub const LEN: usize = 8 + 32 + 204 + 20 + 33 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 204 + 20 + 33 + 8 + 1 + 1 + 8 + 1 + 55;
 }
 
 // ==================== Social Interactions ====================
 
+/// Ephemeral key allowed to act for `owner` in the scoped social instructions.
+/// Lamports above rent form the allowance that reimburses rent the key fronts.
+#[account]
+pub struct SessionKey {
+    pub owner: Pubkey,              // 32
+    pub session_signer: Pubkey,     // 32
+    pub scope: u8,                  // 1 (bitmask of SESSION_SCOPE_*)
+    pub expires_at: i64,            // 8
+    pub allowance: u64,             // 8 (lamports held above rent)
+    pub created_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl SessionKey {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 1 + 1 + 64;
+
+    pub fn allows(&self, scope: u8, now: i64) -> bool {
+        now < self.expires_at && self.scope & scope == scope
+    }
+}
+
 #[account]
 pub struct Follow {
     pub follower: Pubkey,           // 32 - User who is following
     pub following: Pubkey,          // 32 - User being followed
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Follow {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 64;
+}
+
+/// Existence of this PDA bars `blocked` from following, liking, commenting on and tipping `blocker`
+#[account]
+pub struct Block {
+    pub blocker: Pubkey,            // 32
+    pub blocked: Pubkey,            // 32
+    pub created_at: i64,            // 8
+    pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
+}
+
+impl Block {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 64;
 }
 
 #[account]
@@ -458,10 +1064,12 @@ pub struct Like {
     pub post: Pubkey,               // 32 - Post being liked
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Like {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 64;
 }
 
 #[account]
@@ -470,10 +1078,12 @@ pub struct Repost {
     pub original_post: Pubkey,      // 32 - Original post being reposted
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Repost {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 64;
 }
 
 #[account]
@@ -483,8 +1093,10 @@ pub struct Comment {
     pub content: String,            // 4 + 280 = 284 (tweet-length comments)
     pub created_at: i64,            // 8
     pub bump: u8,                   // 1
+    pub version: u8,                // 1
+    pub reserved: [u8; 64],         // 64
 }
 
 impl Comment {
-    pub const LEN: usize = 8 + 32 + 32 + 284 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 284 + 8 + 1 + 1 + 64;
 }
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::*;
+
+// ==================== Username Normalization ====================
+//
+// Usernames are stored and used as PDA seeds in a canonical form: ASCII
+// lowercase letters, digits and a few separators. Restricting the charset to
+// ASCII rules out homoglyph squatting (Cyrillic `а` vs Latin `a`, fullwidth
+// letters, ...) because no two accepted names render alike once lowercased.
+
+/// Separators accepted in profile usernames
+const USERNAME_SEPARATORS: &[char] = &['_'];
+
+/// Separators accepted in username NFT handles
+const HANDLE_SEPARATORS: &[char] = &['_', '-', '.'];
+
+/// Canonical form of a profile username
+pub fn normalize_username(raw: &str) -> Result<String> {
+    canonicalize(raw, USERNAME_SEPARATORS)
+}
+
+/// Canonical form of a username NFT handle
+pub fn normalize_handle(raw: &str) -> Result<String> {
+    canonicalize(raw, HANDLE_SEPARATORS)
+}
+
+/// Seed bytes for a username PDA; invalid names yield an empty seed and are rejected by the handler
+pub fn username_seed(raw: &str) -> String {
+    normalize_username(raw).unwrap_or_default()
+}
+
+fn canonicalize(raw: &str, separators: &[char]) -> Result<String> {
+    require!(!raw.is_empty(), SocialFiError::InvalidUsername);
+    require!(
+        raw.chars().count() <= MAX_USERNAME_LENGTH,
+        SocialFiError::UsernameTooLong
+    );
+
+    raw.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || separators.contains(&c) {
+                Ok(c.to_ascii_lowercase())
+            } else {
+                err!(SocialFiError::InvalidUsername)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_of(result: Result<String>) -> anchor_lang::error::Error {
+        result.unwrap_err()
+    }
+
+    #[test]
+    fn lowercases_ascii_names() {
+        assert_eq!(normalize_username("Alice_99").unwrap(), "alice_99");
+        assert_eq!(normalize_handle("Bob.Sol-1").unwrap(), "bob.sol-1");
+    }
+
+    #[test]
+    fn rejects_latin_names_with_confusable_letters() {
+        // Cyrillic а, Greek ο, Cyrillic е mixed into Latin names
+        for name in ["\u{0430}lice", "b\u{03bf}b", "\u{0435}ve_1"] {
+            assert_eq!(
+                error_of(normalize_username(name)),
+                error!(SocialFiError::InvalidUsername)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_non_ascii_lookalikes() {
+        // All-Cyrillic "асе", fullwidth "ａｌｉｃｅ", fullwidth digit, dotless i
+        for name in ["\u{0430}\u{0441}\u{0435}", "\u{ff41}\u{ff4c}\u{ff49}\u{ff43}\u{ff45}", "user\u{ff11}", "\u{0131}"] {
+            assert!(normalize_username(name).is_err(), "{name} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rejects_separators_outside_the_charset() {
+        assert_eq!(
+            error_of(normalize_username("alice.sol")),
+            error!(SocialFiError::InvalidUsername)
+        );
+        assert!(normalize_username("alice bob").is_err());
+        assert!(normalize_username("").is_err());
+    }
+
+    #[test]
+    fn enforces_maximum_length() {
+        assert!(normalize_username(&"a".repeat(MAX_USERNAME_LENGTH)).is_ok());
+        assert_eq!(
+            error_of(normalize_username(&"a".repeat(MAX_USERNAME_LENGTH + 1))),
+            error!(SocialFiError::UsernameTooLong)
+        );
+    }
+
+    #[test]
+    fn invalid_names_map_to_an_empty_seed() {
+        assert_eq!(username_seed("ALICE"), "alice");
+        assert_eq!(username_seed("\u{0430}lice"), "");
+    }
+}
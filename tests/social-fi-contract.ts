@@ -1,7 +1,22 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { SocialFiContract } from "../target/types/social_fi_contract";
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import {
+  createMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  transfer,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("social-fi-contract", () => {
@@ -24,6 +39,28 @@ describe("social-fi-contract", () => {
   const PROPOSAL_TITLE = "TestProposal";
   const NFT_USERNAME = "rare";
 
+  // Mirrors generate_referral_code: base58 of the first 6 pubkey bytes
+  const referralCodeOf = (wallet: PublicKey) =>
+    anchor.utils.bytes.bs58.encode(wallet.toBuffer().subarray(0, 6));
+
+  const referralCodePda = (code: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("referral_code"), Buffer.from(code)],
+      program.programId
+    )[0];
+
+  const usernameRegistryPda = (username: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("username_registry"), Buffer.from(username.toLowerCase())],
+      program.programId
+    )[0];
+
+  const referralRewardsPda = (wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("referral_rewards"), wallet.toBuffer()],
+      program.programId
+    )[0];
+
   before(async () => {
     // Generate test keypairs
     user1 = Keypair.generate();
@@ -45,11 +82,23 @@ describe("social-fi-contract", () => {
       program.programId
     );
     
+    const [platformStats] = PublicKey.findProgramAddressSync(
+      [Buffer.from("platform_stats")],
+      program.programId
+    );
+
+    const [protocolParams] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_params")],
+      program.programId
+    );
+
     try {
       await program.methods
         .initializePlatform(provider.wallet.publicKey)
         .accounts({
           platformConfig,
+          platformStats,
+          protocolParams,
           admin: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -69,10 +118,15 @@ describe("social-fi-contract", () => {
       );
 
       await program.methods
-        .initializeUser(USERNAME1)
+        .initializeUser(USERNAME1, null)
         .accounts({
           user: user1.publicKey,
           userProfile: user1Profile,
+          usernameRegistry: usernameRegistryPda(USERNAME1),
+          ownReferralCode: referralCodePda(referralCodeOf(user1.publicKey)),
+          ownReferralRewards: referralRewardsPda(user1.publicKey),
+          referrerCode: null,
+          referrerProfile: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user1])
@@ -88,16 +142,32 @@ describe("social-fi-contract", () => {
         program.programId
       );
 
+      // User 2 signs up with user 1's referral code
+      const user1Code = referralCodeOf(user1.publicKey);
+
       await program.methods
-        .initializeUser(USERNAME2)
+        .initializeUser(USERNAME2, user1Code)
         .accounts({
           user: user2.publicKey,
           userProfile: user2Profile,
+          usernameRegistry: usernameRegistryPda(USERNAME2),
+          ownReferralCode: referralCodePda(referralCodeOf(user2.publicKey)),
+          ownReferralRewards: referralRewardsPda(user2.publicKey),
+          referrerCode: referralCodePda(user1Code),
+          referrerProfile: user1Profile,
           systemProgram: SystemProgram.programId,
         })
         .signers([user2])
         .rpc();
 
+      const registry = await program.account.usernameRegistry.fetch(usernameRegistryPda(USERNAME1));
+      expect(registry.profile.toString()).to.equal(user1Profile.toString());
+
+      const referred = await program.account.userProfile.fetch(user2Profile);
+      expect(referred.referredBy.toString()).to.equal(user1.publicKey.toString());
+      const referrer = await program.account.userProfile.fetch(user1Profile);
+      expect(referrer.referralsCount.toNumber()).to.equal(1);
+
       // Creator
       const [creatorProfile] = PublicKey.findProgramAddressSync(
         [Buffer.from("user_profile"), creator.publicKey.toBuffer()],
@@ -105,16 +175,87 @@ describe("social-fi-contract", () => {
       );
 
       await program.methods
-        .initializeUser(CREATOR_USERNAME)
+        .initializeUser(CREATOR_USERNAME, null)
         .accounts({
           user: creator.publicKey,
           userProfile: creatorProfile,
+          usernameRegistry: usernameRegistryPda(CREATOR_USERNAME),
+          ownReferralCode: referralCodePda(referralCodeOf(creator.publicKey)),
+          ownReferralRewards: referralRewardsPda(creator.publicKey),
+          referrerCode: null,
+          referrerProfile: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([creator])
         .rpc();
     });
 
+    it("Rejects a username that matches an existing one after normalization", async () => {
+      const squatter = anchor.web3.Keypair.generate();
+      const sig = await provider.connection.requestAirdrop(squatter.publicKey, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+      const [squatterProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), squatter.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializeUser(USERNAME1.toUpperCase(), null)
+          .accounts({
+            user: squatter.publicKey,
+            userProfile: squatterProfile,
+            usernameRegistry: usernameRegistryPda(USERNAME1),
+            ownReferralCode: referralCodePda(referralCodeOf(squatter.publicKey)),
+            ownReferralRewards: referralRewardsPda(squatter.publicKey),
+            referrerCode: null,
+            referrerProfile: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([squatter])
+          .rpc();
+        expect.fail("Expected UsernameAlreadyTaken");
+      } catch (err) {
+        expect(err.toString()).to.include("UsernameAlreadyTaken");
+      }
+    });
+
+    it("Renames a profile and releases the old username", async () => {
+      const [user2Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      const newUsername = USERNAME2 + "_x";
+
+      await program.methods
+        .changeUsername(newUsername)
+        .accounts({
+          userProfile: user2Profile,
+          previousRegistry: usernameRegistryPda(USERNAME2),
+          usernameRegistry: usernameRegistryPda(newUsername),
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const profile = await program.account.userProfile.fetch(user2Profile);
+      expect(profile.username).to.equal(newUsername);
+      const released = await provider.connection.getAccountInfo(usernameRegistryPda(USERNAME2));
+      expect(released).to.be.null;
+
+      // Rename back so later tests see the original name
+      await program.methods
+        .changeUsername(USERNAME2)
+        .accounts({
+          userProfile: user2Profile,
+          previousRegistry: usernameRegistryPda(newUsername),
+          usernameRegistry: usernameRegistryPda(USERNAME2),
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+    });
+
     it("Sends a tip", async () => {
       const [user1Profile] = PublicKey.findProgramAddressSync(
         [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
@@ -136,12 +277,381 @@ describe("social-fi-contract", () => {
           recipient: creator.publicKey,
           recipientProfile: creatorProfile,
           systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
         })
         .signers([user1])
         .rpc();
 
+      // Recipient is credited the tip net of the platform tip fee
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+      const config = await program.account.platformConfig.fetch(platformConfig);
+      const fee = tipAmount.mul(config.feeSchedule.tipBps).div(new BN(10000));
+
+      const [platformStats] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_stats")],
+        program.programId
+      );
+      const stats = await program.account.platformStats.fetch(platformStats);
+      expect(stats.totalUsers.toNumber()).to.be.at.least(3);
+      expect(stats.totalTipVolume.gte(tipAmount)).to.be.true;
+
       const recipientProfile = await program.account.userProfile.fetch(creatorProfile);
-      expect(recipientProfile.totalTipsReceived.toNumber()).to.equal(tipAmount.toNumber());
+      expect(recipientProfile.totalTipsReceived.toNumber()).to.equal(tipAmount.sub(fee).toNumber());
+    });
+
+    it("Tips several recipients in one batch", async () => {
+      const profileOf = (wallet: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("user_profile"), wallet.toBuffer()],
+          program.programId
+        )[0];
+      const blockedOf = (wallet: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("blocked"), wallet.toBuffer()],
+          program.programId
+        )[0];
+      const blockOf = (wallet: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("block"), wallet.toBuffer(), user1.publicKey.toBuffer()],
+          program.programId
+        )[0];
+      const recipients = [user2.publicKey, creator.publicKey];
+      const before = await Promise.all(
+        recipients.map((wallet) => program.account.userProfile.fetch(profileOf(wallet)))
+      );
+      const senderBefore = await program.account.userProfile.fetch(profileOf(user1.publicKey));
+      const amount = new BN(0.01 * LAMPORTS_PER_SOL);
+
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+      const config = await program.account.platformConfig.fetch(platformConfig);
+      const fee = amount.mul(config.feeSchedule.tipBps).div(new BN(10000));
+
+      await program.methods
+        .sendTipsBatch(recipients.map((recipient) => ({ recipient, amount })))
+        .accounts({
+          sender: user1.publicKey,
+          feeCollector: provider.wallet.publicKey,
+        })
+        .remainingAccounts(
+          recipients.flatMap((wallet) => [
+            { pubkey: wallet, isSigner: false, isWritable: true },
+            { pubkey: profileOf(wallet), isSigner: false, isWritable: true },
+            { pubkey: blockedOf(wallet), isSigner: false, isWritable: false },
+            { pubkey: blockOf(wallet), isSigner: false, isWritable: false },
+          ])
+        )
+        .signers([user1])
+        .rpc();
+
+      for (const [i, wallet] of recipients.entries()) {
+        const after = await program.account.userProfile.fetch(profileOf(wallet));
+        expect(after.totalTipsReceived.sub(before[i].totalTipsReceived).toString())
+          .to.equal(amount.sub(fee).toString());
+      }
+      const senderAfter = await program.account.userProfile.fetch(profileOf(user1.publicKey));
+      expect(senderAfter.totalTipsSent.sub(senderBefore.totalTipsSent).toString())
+        .to.equal(amount.muln(recipients.length).toString());
+    });
+
+    it("Escrows a tip stream, refuses early cranks and refunds on cancel", async () => {
+      const [tipStream] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tip_stream"), user2.publicKey.toBuffer(), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const rate = new BN(0.1 * LAMPORTS_PER_SOL);
+      const week = new BN(7 * 24 * 60 * 60);
+      const deposit = rate.muln(4);
+
+      await program.methods
+        .createTipStream(rate, week, deposit)
+        .accounts({
+          tipStream,
+          sender: user2.publicKey,
+          recipient: creator.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const stream = await program.account.tipStream.fetch(tipStream);
+      expect(stream.balance.toString()).to.equal(deposit.toString());
+
+      // An open stream keeps the recipient from closing their profile
+      const [creatorProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const streamsBefore = (await program.account.userProfile.fetch(creatorProfile)).activeTipStreams;
+      expect(streamsBefore).to.be.greaterThan(0);
+
+      // The first period has not elapsed yet
+      try {
+        await program.methods
+          .crankTipStream()
+          .accounts({
+            tipStream,
+            recipient: creator.publicKey,
+            feeCollector: provider.wallet.publicKey,
+            cranker: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Expected TipStreamNotDue");
+      } catch (err) {
+        expect(err.toString()).to.include("TipStreamNotDue");
+      }
+
+      await program.methods
+        .cancelTipStream()
+        .accounts({
+          tipStream,
+          recipient: creator.publicKey,
+          sender: user2.publicKey,
+          feeCollector: provider.wallet.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(tipStream)).to.be.null;
+      const streamsAfter = (await program.account.userProfile.fetch(creatorProfile)).activeTipStreams;
+      expect(streamsAfter).to.equal(streamsBefore - 1);
+    });
+
+    it("Sends a token tip in an allow-listed mint", async () => {
+      const payer = (provider.wallet as anchor.Wallet).payer;
+      const mint = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+      const senderAta = await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, user1.publicKey);
+      await mintTo(provider.connection, payer, mint, senderAta.address, payer, 10_000_000);
+
+      const [tipMint] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tip_mint"), mint.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .addTipMint()
+        .accounts({
+          mint,
+          tipMint,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const amount = new BN(1_000_000);
+      await program.methods
+        .sendTokenTip(amount)
+        .accounts({
+          sender: user1.publicKey,
+          recipient: creator.publicKey,
+          mint,
+          tipMint,
+          senderTokenAccount: senderAta.address,
+          feeCollector: provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      const totals = await program.account.tipMint.fetch(tipMint);
+      expect(totals.totalTipped.toString()).to.equal(amount.toString());
+      expect(totals.tipCount.toNumber()).to.equal(1);
+
+      const recipientAta = getAssociatedTokenAddressSync(mint, creator.publicKey);
+      const balance = await provider.connection.getTokenAccountBalance(recipientAta);
+      expect(Number(balance.value.amount)).to.be.greaterThan(0);
+    });
+
+    it("Updates profile fields and resizes the account", async () => {
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const sizeBefore = (await provider.connection.getAccountInfo(user1Profile)).data.length;
+
+      await program.methods
+        .updateProfile("Alice", "Building on Solana", "https://example.com/a.png", null, "https://example.com")
+        .accounts({
+          userProfile: user1Profile,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      let profile = await program.account.userProfile.fetch(user1Profile);
+      expect(profile.displayName).to.equal("Alice");
+      expect(profile.bio).to.equal("Building on Solana");
+      expect(profile.bannerUri).to.equal("");
+      const grown = (await provider.connection.getAccountInfo(user1Profile)).data.length;
+      expect(grown).to.be.greaterThan(sizeBefore);
+
+      // Clearing the bio shrinks the account again
+      await program.methods
+        .updateProfile(null, "", null, null, null)
+        .accounts({
+          userProfile: user1Profile,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      profile = await program.account.userProfile.fetch(user1Profile);
+      expect(profile.displayName).to.equal("Alice");
+      expect(profile.bio).to.equal("");
+      const shrunk = (await provider.connection.getAccountInfo(user1Profile)).data.length;
+      expect(shrunk).to.equal(grown - "Building on Solana".length);
+    });
+
+    it("Closes a profile and releases its username", async () => {
+      const leaver = anchor.web3.Keypair.generate();
+      const sig = await provider.connection.requestAirdrop(leaver.publicKey, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+      const [leaverProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), leaver.publicKey.toBuffer()],
+        program.programId
+      );
+      const username = "leaving_soon";
+
+      await program.methods
+        .initializeUser(username, null)
+        .accounts({
+          user: leaver.publicKey,
+          userProfile: leaverProfile,
+          usernameRegistry: usernameRegistryPda(username),
+          ownReferralCode: referralCodePda(referralCodeOf(leaver.publicKey)),
+          ownReferralRewards: referralRewardsPda(leaver.publicKey),
+          referrerCode: null,
+          referrerProfile: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([leaver])
+        .rpc();
+
+      await program.methods
+        .closeUserProfile()
+        .accounts({
+          userProfile: leaverProfile,
+          usernameRegistry: usernameRegistryPda(username),
+          owner: leaver.publicKey,
+        })
+        .signers([leaver])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(leaverProfile)).to.be.null;
+      expect(await provider.connection.getAccountInfo(usernameRegistryPda(username))).to.be.null;
+      // Referral accounts outlive the profile so referred users can keep trading
+      expect(await provider.connection.getAccountInfo(referralCodePda(referralCodeOf(leaver.publicKey)))).to.not.be.null;
+      expect(await provider.connection.getAccountInfo(referralRewardsPda(leaver.publicKey))).to.not.be.null;
+
+      // Signing up again reuses them
+      await program.methods
+        .initializeUser(username, null)
+        .accounts({
+          user: leaver.publicKey,
+          userProfile: leaverProfile,
+          usernameRegistry: usernameRegistryPda(username),
+          ownReferralCode: referralCodePda(referralCodeOf(leaver.publicKey)),
+          ownReferralRewards: referralRewardsPda(leaver.publicKey),
+          referrerCode: null,
+          referrerProfile: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([leaver])
+        .rpc();
+      expect((await program.account.userProfile.fetch(leaverProfile)).username).to.equal(username);
+    });
+
+    it("Closes a followed profile and still lets its followers unfollow", async () => {
+      const followed = anchor.web3.Keypair.generate();
+      const sig = await provider.connection.requestAirdrop(followed.publicKey, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+      const [followedProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), followed.publicKey.toBuffer()],
+        program.programId
+      );
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const [follow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow"), user1.publicKey.toBuffer(), followed.publicKey.toBuffer()],
+        program.programId
+      );
+      const username = "followed_leaver";
+
+      await program.methods
+        .initializeUser(username, null)
+        .accounts({
+          user: followed.publicKey,
+          userProfile: followedProfile,
+          usernameRegistry: usernameRegistryPda(username),
+          ownReferralCode: referralCodePda(referralCodeOf(followed.publicKey)),
+          ownReferralRewards: referralRewardsPda(followed.publicKey),
+          referrerCode: null,
+          referrerProfile: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([followed])
+        .rpc();
+      await program.methods
+        .followUser()
+        .accounts({
+          follow,
+          follower: user1.publicKey,
+          signer: user1.publicKey,
+          following: followed.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+      const before = await program.account.userProfile.fetch(user1Profile);
+
+      // Followers do not keep a profile open
+      await program.methods
+        .closeUserProfile()
+        .accounts({
+          userProfile: followedProfile,
+          usernameRegistry: usernameRegistryPda(username),
+          owner: followed.publicKey,
+        })
+        .signers([followed])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(followedProfile)).to.be.null;
+
+      await program.methods
+        .unfollowUser()
+        .accounts({
+          follow,
+          follower: user1.publicKey,
+          following: followed.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(follow)).to.be.null;
+      const after = await program.account.userProfile.fetch(user1Profile);
+      expect(after.followingCount.toString()).to.equal(before.followingCount.subn(1).toString());
+    });
+
+    it("Rejects an over-long bio", async () => {
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .updateProfile(null, "x".repeat(161), null, null, null)
+          .accounts({
+            userProfile: user1Profile,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("Expected ProfileFieldTooLong");
+      } catch (err) {
+        expect(err.toString()).to.include("ProfileFieldTooLong");
+      }
     });
   });
 
@@ -207,6 +717,7 @@ describe("social-fi-contract", () => {
           creator: creator.publicKey,
           platformConfig,
           systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
         })
         .signers([user1])
         .rpc();
@@ -252,6 +763,7 @@ describe("social-fi-contract", () => {
           creator: creator.publicKey,
           platformConfig,
           systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
         })
         .signers([user1])
         .rpc();
@@ -310,6 +822,7 @@ describe("social-fi-contract", () => {
           subscriber: user1.publicKey,
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
         })
         .signers([user1])
         .rpc();
@@ -318,6 +831,79 @@ describe("social-fi-contract", () => {
       expect(sub.subscriber.toString()).to.equal(user1.publicKey.toString());
     });
 
+    it("Credits the referrer a share of a referred user's subscription fee", async () => {
+      const tierId = new BN(1);
+      const [subscriptionTier] = PublicKey.findProgramAddressSync(
+        [Buffer.from("subscription_tier"), creator.publicKey.toBuffer(), tierId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [subscription] = PublicKey.findProgramAddressSync(
+        [Buffer.from("subscription"), user2.publicKey.toBuffer(), creator.publicKey.toBuffer(), tierId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [user2Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      // user2 was referred by user1 at onboarding
+      const [referralRewards] = PublicKey.findProgramAddressSync(
+        [Buffer.from("referral_rewards"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Leaving out the referrer's rewards PDA does not skip the cut
+      try {
+        await program.methods
+          .subscribe()
+          .accounts({
+            subscription,
+            subscriptionTier,
+            subscriber: user2.publicKey,
+            creator: creator.publicKey,
+            subscriberProfile: user2Profile,
+            referralRewards: null,
+            systemProgram: SystemProgram.programId,
+            feeCollector: provider.wallet.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+        expect.fail("Expected ReferralRewardsRequired");
+      } catch (e) {
+        expect(e.toString()).to.include("ReferralRewardsRequired");
+      }
+
+      await program.methods
+        .subscribe()
+        .accounts({
+          subscription,
+          subscriptionTier,
+          subscriber: user2.publicKey,
+          creator: creator.publicKey,
+          subscriberProfile: user2Profile,
+          referralRewards,
+          systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const rewards = await program.account.referralRewards.fetch(referralRewards);
+      expect(rewards.unclaimed.toNumber()).to.be.greaterThan(0);
+
+      await program.methods
+        .claimReferralRewards()
+        .accounts({
+          referralRewards,
+          referrer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const claimed = await program.account.referralRewards.fetch(referralRewards);
+      expect(claimed.unclaimed.toNumber()).to.equal(0);
+      expect(claimed.totalClaimed.toString()).to.equal(rewards.unclaimed.toString());
+    });
+
     it("Cancels subscription", async () => {
       const tierId = new BN(1);
       const [subscriptionTier] = PublicKey.findProgramAddressSync(
@@ -391,6 +977,7 @@ describe("social-fi-contract", () => {
           groupCreator: creator.publicKey,
           member: user1.publicKey,
           systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
         })
         .signers([user1])
         .rpc();
@@ -520,38 +1107,355 @@ describe("social-fi-contract", () => {
     });
   });
 
-  describe("Username NFT Marketplace", () => {
-    it("Mints username NFT", async () => {
-      const [usernameNft] = PublicKey.findProgramAddressSync(
-        [Buffer.from("username_nft"), Buffer.from(NFT_USERNAME)],
+  describe("Posts", () => {
+    it("Tips a post and credits its author", async () => {
+      const nonce = "tipme";
+      const [post] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), creator.publicKey.toBuffer(), Buffer.from(nonce)],
         program.programId
       );
 
       await program.methods
-        .mintUsername(NFT_USERNAME)
+        .createPost(nonce, "ipfs://post-metadata")
         .accounts({
-          usernameNft,
-          owner: user1.publicKey,
-          systemProgram: SystemProgram.programId,
+          post,
+          author: creator.publicKey,
         })
-        .signers([user1])
+        .signers([creator])
         .rpc();
 
-      const nft = await program.account.usernameNft.fetch(usernameNft);
-      expect(nft.username).to.equal(NFT_USERNAME);
-      expect(nft.owner.toString()).to.equal(user1.publicKey.toString());
-    });
-
-    it("Lists username NFT", async () => {
-      const [usernameNft] = PublicKey.findProgramAddressSync(
-        [Buffer.from("username_nft"), Buffer.from(NFT_USERNAME)],
+      const [creatorProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), creator.publicKey.toBuffer()],
         program.programId
       );
+      const before = await program.account.userProfile.fetch(creatorProfile);
+      const amount = new BN(0.05 * LAMPORTS_PER_SOL);
 
-      const [listing] = PublicKey.findProgramAddressSync(
-        [Buffer.from("listing"), usernameNft.toBuffer()],
-        program.programId
-      );
+      await program.methods
+        .tipPost(amount, "great thread")
+        .accounts({
+          post,
+          sender: user1.publicKey,
+          author: creator.publicKey,
+          feeCollector: provider.wallet.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const tipped = await program.account.post.fetch(post);
+      expect(tipped.tipsReceived.toNumber()).to.be.greaterThan(0);
+      const after = await program.account.userProfile.fetch(creatorProfile);
+      expect(after.totalTipsReceived.sub(before.totalTipsReceived).toString()).to.equal(
+        tipped.tipsReceived.toString()
+      );
+    });
+
+    it("Likes a post through a scoped session key", async () => {
+      const nonce = "session";
+      const [post] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), creator.publicKey.toBuffer(), Buffer.from(nonce)],
+        program.programId
+      );
+      await program.methods
+        .createPost(nonce, "ipfs://session-post")
+        .accounts({
+          post,
+          author: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      const sessionSigner = Keypair.generate();
+      await provider.connection.requestAirdrop(sessionSigner.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      const [sessionKey] = PublicKey.findProgramAddressSync(
+        [Buffer.from("session_key"), user1.publicKey.toBuffer(), sessionSigner.publicKey.toBuffer()],
+        program.programId
+      );
+      const SESSION_SCOPE_LIKE = 1 << 1;
+      const allowance = new BN(0.01 * LAMPORTS_PER_SOL);
+
+      await program.methods
+        .createSessionKey(sessionSigner.publicKey, SESSION_SCOPE_LIKE, new BN(60 * 60), allowance)
+        .accounts({
+          sessionKey,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const [like] = PublicKey.findProgramAddressSync(
+        [Buffer.from("like"), user1.publicKey.toBuffer(), post.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .likePost()
+        .accounts({
+          like,
+          user: user1.publicKey,
+          signer: sessionSigner.publicKey,
+          sessionKey,
+          post,
+        })
+        .signers([sessionSigner])
+        .rpc();
+
+      const liked = await program.account.like.fetch(like);
+      expect(liked.user.toString()).to.equal(user1.publicKey.toString());
+      const session = await program.account.sessionKey.fetch(sessionKey);
+      expect(session.allowance.lt(allowance)).to.be.true;
+
+      // Following is outside the session's scope
+      const [follow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow"), user1.publicKey.toBuffer(), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      try {
+        await program.methods
+          .followUser()
+          .accounts({
+            follow,
+            follower: user1.publicKey,
+            signer: sessionSigner.publicKey,
+            sessionKey,
+            following: creator.publicKey,
+          })
+          .signers([sessionSigner])
+          .rpc();
+        expect.fail("Expected SessionKeyNotAllowed");
+      } catch (err) {
+        expect(err.toString()).to.include("SessionKeyNotAllowed");
+      }
+
+      await program.methods
+        .revokeSessionKey()
+        .accounts({
+          sessionKey,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(sessionKey)).to.be.null;
+    });
+
+    it("Blocks a follower, removing the follow and tip stream and refusing their tips", async () => {
+      const [follow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("follow"), user2.publicKey.toBuffer(), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const [tipStream] = PublicKey.findProgramAddressSync(
+        [Buffer.from("tip_stream"), user2.publicKey.toBuffer(), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const [block] = PublicKey.findProgramAddressSync(
+        [Buffer.from("block"), creator.publicKey.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      const [creatorProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .followUser()
+        .accounts({
+          follow,
+          follower: user2.publicKey,
+          signer: user2.publicKey,
+          following: creator.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+      const createStream = () =>
+        program.methods
+          .createTipStream(new BN(0.01 * LAMPORTS_PER_SOL), new BN(7 * 24 * 60 * 60), new BN(0.02 * LAMPORTS_PER_SOL))
+          .accounts({
+            tipStream,
+            sender: user2.publicKey,
+            recipient: creator.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+      await createStream();
+      const before = await program.account.userProfile.fetch(creatorProfile);
+
+      await program.methods
+        .blockUser()
+        .accounts({
+          block,
+          blocker: creator.publicKey,
+          blocked: user2.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(follow)).to.be.null;
+      expect(await provider.connection.getAccountInfo(tipStream)).to.be.null;
+      const after = await program.account.userProfile.fetch(creatorProfile);
+      expect(after.followersCount.toString()).to.equal(before.followersCount.subn(1).toString());
+      expect(after.activeTipStreams).to.equal(before.activeTipStreams - 1);
+
+      try {
+        await createStream();
+        expect.fail("Expected BlockedByUser");
+      } catch (err) {
+        expect(err.toString()).to.include("BlockedByUser");
+      }
+
+      try {
+        await program.methods
+          .sendTip(new BN(0.01 * LAMPORTS_PER_SOL))
+          .accounts({
+            sender: user2.publicKey,
+            recipient: creator.publicKey,
+            feeCollector: provider.wallet.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+        expect.fail("Expected BlockedByUser");
+      } catch (err) {
+        expect(err.toString()).to.include("BlockedByUser");
+      }
+
+      await program.methods
+        .unblockUser()
+        .accounts({
+          block,
+          blocker: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(block)).to.be.null;
+    });
+  });
+
+  describe("Username NFT Marketplace", () => {
+    it("Mints username NFT", async () => {
+      const [usernameNft] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_nft"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
+
+      await program.methods
+        .mintUsername(NFT_USERNAME)
+        .accounts({
+          usernameNft,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const nft = await program.account.usernameNft.fetch(usernameNft);
+      expect(nft.username).to.equal(NFT_USERNAME);
+      expect(nft.owner.toString()).to.equal(user1.publicKey.toString());
+    });
+
+    it("Sets the username NFT as the holder's primary username", async () => {
+      const [usernameNft] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_nft"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const [usernameResolver] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_resolver"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
+      const nft = await program.account.usernameNft.fetch(usernameNft);
+
+      await program.methods
+        .setPrimaryUsername()
+        .accounts({
+          userProfile: user1Profile,
+          usernameNft,
+          tokenAccount: getAssociatedTokenAddressSync(nft.mint, user1.publicKey),
+          usernameResolver,
+          previousResolver: null,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const profile = await program.account.userProfile.fetch(user1Profile);
+      expect(profile.primaryUsernameMint.toString()).to.equal(nft.mint.toString());
+      const resolver = await program.account.usernameResolver.fetch(usernameResolver);
+      expect(resolver.profile.toString()).to.equal(user1Profile.toString());
+    });
+
+    it("Closes the previous resolver when switching primary usernames", async () => {
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const nftPda = (name: string) =>
+        PublicKey.findProgramAddressSync([Buffer.from("username_nft"), Buffer.from(name)], program.programId)[0];
+      const resolverPda = (name: string) =>
+        PublicKey.findProgramAddressSync([Buffer.from("username_resolver"), Buffer.from(name)], program.programId)[0];
+      const secondUsername = "rare_two";
+
+      await program.methods
+        .mintUsername(secondUsername)
+        .accounts({
+          usernameNft: nftPda(secondUsername),
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const bind = async (name: string, previousResolver: PublicKey | null) => {
+        const nft = await program.account.usernameNft.fetch(nftPda(name));
+        await program.methods
+          .setPrimaryUsername()
+          .accounts({
+            userProfile: user1Profile,
+            usernameNft: nftPda(name),
+            tokenAccount: getAssociatedTokenAddressSync(nft.mint, user1.publicKey),
+            usernameResolver: resolverPda(name),
+            previousResolver,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+        return nft.mint;
+      };
+
+      // Switching without the current resolver would leave it stale
+      try {
+        await bind(secondUsername, null);
+        expect.fail("Expected InvalidPreviousResolver");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPreviousResolver");
+      }
+
+      const secondMint = await bind(secondUsername, resolverPda(NFT_USERNAME));
+      expect(await provider.connection.getAccountInfo(resolverPda(NFT_USERNAME))).to.be.null;
+      let profile = await program.account.userProfile.fetch(user1Profile);
+      expect(profile.primaryUsernameMint.toString()).to.equal(secondMint.toString());
+
+      // Switch back so the marketplace tests below sell the bound name
+      const firstMint = await bind(NFT_USERNAME, resolverPda(secondUsername));
+      expect(await provider.connection.getAccountInfo(resolverPda(secondUsername))).to.be.null;
+      profile = await program.account.userProfile.fetch(user1Profile);
+      expect(profile.primaryUsernameMint.toString()).to.equal(firstMint.toString());
+      const resolver = await program.account.usernameResolver.fetch(resolverPda(NFT_USERNAME));
+      expect(resolver.profile.toString()).to.equal(user1Profile.toString());
+    });
+
+    it("Lists username NFT", async () => {
+      const [usernameNft] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_nft"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
+
+      const [listing] = PublicKey.findProgramAddressSync(
+        [Buffer.from("listing"), usernameNft.toBuffer()],
+        program.programId
+      );
 
       const price = new BN(1 * LAMPORTS_PER_SOL);
 
@@ -624,6 +1528,14 @@ describe("social-fi-contract", () => {
         [Buffer.from("platform_config")],
         program.programId
       );
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const [usernameResolver] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_resolver"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
 
       await program.methods
         .acceptOffer()
@@ -633,8 +1545,10 @@ describe("social-fi-contract", () => {
           offer,
           seller: user1.publicKey,
           buyer: user2.publicKey,
+          usernameResolver,
           platformConfig,
           systemProgram: SystemProgram.programId,
+          feeCollector: provider.wallet.publicKey,
         })
         .signers([user1, user2])
         .rpc();
@@ -642,6 +1556,657 @@ describe("social-fi-contract", () => {
       // Verify NFT ownership transferred
       const nft = await program.account.usernameNft.fetch(usernameNft);
       expect(nft.owner.toString()).to.equal(user2.publicKey.toString());
+
+      // The sale drops the seller's primary username binding
+      const profile = await program.account.userProfile.fetch(user1Profile);
+      expect(profile.primaryUsernameMint.toString()).to.equal(PublicKey.default.toString());
+      expect(await provider.connection.getAccountInfo(usernameResolver)).to.be.null;
+    });
+
+    it("Lets anyone clear a primary username after the NFT leaves the wallet", async () => {
+      const [usernameNft] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_nft"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
+      const [user2Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      const [usernameResolver] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username_resolver"), Buffer.from(NFT_USERNAME)],
+        program.programId
+      );
+      const nft = await program.account.usernameNft.fetch(usernameNft);
+      const user2TokenAccount = getAssociatedTokenAddressSync(nft.mint, user2.publicKey);
+
+      await program.methods
+        .setPrimaryUsername()
+        .accounts({
+          userProfile: user2Profile,
+          usernameNft,
+          tokenAccount: user2TokenAccount,
+          usernameResolver,
+          previousResolver: null,
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      // Move the NFT outside the marketplace; the recorded owner is now stale
+      const user1TokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        user1,
+        nft.mint,
+        user1.publicKey
+      );
+      await transfer(provider.connection, user2, user2TokenAccount, user1TokenAccount.address, user2, 1);
+
+      // The previous holder's token account no longer proves anything
+      try {
+        await program.methods
+          .clearPrimaryUsername()
+          .accounts({
+            userProfile: user2Profile,
+            usernameNft,
+            usernameResolver,
+            holderTokenAccount: user2TokenAccount,
+            profileOwner: user2.publicKey,
+            signer: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("Expected NotUsernameOwner");
+      } catch (e) {
+        expect(e.toString()).to.include("NotUsernameOwner");
+      }
+
+      await program.methods
+        .clearPrimaryUsername()
+        .accounts({
+          userProfile: user2Profile,
+          usernameNft,
+          usernameResolver,
+          holderTokenAccount: user1TokenAccount.address,
+          profileOwner: user2.publicKey,
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const profile = await program.account.userProfile.fetch(user2Profile);
+      expect(profile.primaryUsernameMint.toString()).to.equal(PublicKey.default.toString());
+      expect(await provider.connection.getAccountInfo(usernameResolver)).to.be.null;
+    });
+  });
+
+  describe("Platform Administration", () => {
+    it("Proposes and cancels an admin transfer", async () => {
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+
+      await program.methods
+        .proposeAdmin(user1.publicKey)
+        .accounts({
+          platformConfig,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      let config = await program.account.platformConfig.fetch(platformConfig);
+      expect(config.pendingAdmin.toString()).to.equal(user1.publicKey.toString());
+
+      // Timelock has not elapsed, so the pending admin cannot accept yet
+      try {
+        await program.methods
+          .acceptAdmin()
+          .accounts({
+            platformConfig,
+            newAdmin: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+        expect.fail("accept_admin should fail before the timelock");
+      } catch (e) {
+        expect(e.toString()).to.include("AdminTransferTimelocked");
+      }
+
+      await program.methods
+        .cancelAdminTransfer()
+        .accounts({
+          platformConfig,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      config = await program.account.platformConfig.fetch(platformConfig);
+      expect(config.pendingAdmin).to.be.null;
+      expect(config.admin.toString()).to.equal(provider.wallet.publicKey.toString());
+    });
+
+    it("Pauses and resumes a single module", async () => {
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+      const PAUSE_MARKETPLACE = 1 << 4;
+
+      await program.methods
+        .pauseModules(PAUSE_MARKETPLACE)
+        .accounts({
+          platformConfig,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      let config = await program.account.platformConfig.fetch(platformConfig);
+      expect(config.pausedModules).to.equal(PAUSE_MARKETPLACE);
+
+      await program.methods
+        .unpauseModules(PAUSE_MARKETPLACE)
+        .accounts({
+          platformConfig,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      config = await program.account.platformConfig.fetch(platformConfig);
+      expect(config.pausedModules).to.equal(0);
+    });
+
+    it("Lets the guardian pause but not unpause", async () => {
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+
+      await program.methods
+        .setGuardian(user2.publicKey)
+        .accounts({
+          platformConfig,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .pausePlatform()
+        .accounts({
+          platformConfig,
+          authority: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      let config = await program.account.platformConfig.fetch(platformConfig);
+      expect(config.pausedModules).to.equal(0xff);
+
+      try {
+        await program.methods
+          .unpausePlatform()
+          .accounts({
+            platformConfig,
+            admin: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+        expect.fail("guardian should not be able to unpause");
+      } catch (e) {
+        expect(e.toString()).to.include("Unauthorized");
+      }
+
+      await program.methods
+        .unpausePlatform()
+        .accounts({
+          platformConfig,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      config = await program.account.platformConfig.fetch(platformConfig);
+      expect(config.pausedModules).to.equal(0);
+    });
+
+    it("Updates protocol params within bounds", async () => {
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+      const [protocolParams] = PublicKey.findProgramAddressSync(
+        [Buffer.from("protocol_params")],
+        program.programId
+      );
+
+      const previous = await program.account.protocolParams.fetch(protocolParams);
+      const newBasePrice = new BN(20_000_000);
+
+      await program.methods
+        .updateBasePrice(newBasePrice)
+        .accounts({
+          platformConfig,
+          protocolParams,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      let params = await program.account.protocolParams.fetch(protocolParams);
+      expect(params.basePrice.toString()).to.equal(newBasePrice.toString());
+
+      // APYs must not decrease with a longer lock
+      try {
+        await program.methods
+          .updateStakingRates(
+            params.lockMultipliersBps,
+            [new BN(3000), new BN(2000), new BN(1500), new BN(1000), new BN(500)]
+          )
+          .accounts({
+            platformConfig,
+            protocolParams,
+            admin: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("decreasing APY table should be rejected");
+      } catch (e) {
+        expect(e.toString()).to.include("ProtocolParamOutOfRange");
+      }
+
+      await program.methods
+        .updateBasePrice(previous.basePrice)
+        .accounts({
+          platformConfig,
+          protocolParams,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      params = await program.account.protocolParams.fetch(protocolParams);
+      expect(params.basePrice.toString()).to.equal(previous.basePrice.toString());
+    });
+
+    it("Lets an approved attester verify a creator and revoke it", async () => {
+      const attester = user2;
+      const [attesterPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("attester"), attester.publicKey.toBuffer()],
+        program.programId
+      );
+      const [verification] = PublicKey.findProgramAddressSync(
+        [Buffer.from("verification"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const [creatorProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const VERIFICATION_KIND_CREATOR = 2;
+      const expiresAt = new BN(Math.floor(Date.now() / 1000) + 30 * 24 * 60 * 60);
+
+      // Not an attester yet
+      try {
+        await program.methods
+          .issueVerification(VERIFICATION_KIND_CREATOR, expiresAt)
+          .accounts({
+            attester: attesterPda,
+            userProfile: creatorProfile,
+            verification,
+            authority: attester.publicKey,
+          })
+          .signers([attester])
+          .rpc();
+        expect.fail("Expected an unapproved attester to be rejected");
+      } catch (err) {
+        expect(err.toString()).to.include("AccountNotInitialized");
+      }
+
+      await program.methods
+        .addAttester(attester.publicKey)
+        .accounts({
+          attester: attesterPda,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .issueVerification(VERIFICATION_KIND_CREATOR, expiresAt)
+        .accounts({
+          attester: attesterPda,
+          userProfile: creatorProfile,
+          verification,
+          authority: attester.publicKey,
+        })
+        .signers([attester])
+        .rpc();
+
+      const issued = await program.account.verification.fetch(verification);
+      expect(issued.kind).to.equal(VERIFICATION_KIND_CREATOR);
+      expect(issued.revoked).to.be.false;
+
+      const nonce = "verified";
+      const [post] = PublicKey.findProgramAddressSync(
+        [Buffer.from("post"), creator.publicKey.toBuffer(), Buffer.from(nonce)],
+        program.programId
+      );
+      await program.methods
+        .createPost(nonce, "ipfs://verified-post")
+        .accounts({
+          post,
+          author: creator.publicKey,
+          authorVerification: verification,
+        })
+        .signers([creator])
+        .rpc();
+      expect((await program.account.post.fetch(post)).authorVerified).to.be.true;
+
+      await program.methods
+        .revokeVerification()
+        .accounts({
+          verification,
+          signer: provider.wallet.publicKey,
+        })
+        .rpc();
+      const revoked = await program.account.verification.fetch(verification);
+      expect(revoked.revoked).to.be.true;
+      expect(revoked.revokedBy.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
+      expect(revoked.adminRevoked).to.be.true;
+
+      // The attester cannot undo an admin revocation by re-issuing
+      try {
+        await program.methods
+          .issueVerification(VERIFICATION_KIND_CREATOR, expiresAt)
+          .accounts({
+            attester: attesterPda,
+            userProfile: creatorProfile,
+            verification,
+            authority: attester.publicKey,
+          })
+          .signers([attester])
+          .rpc();
+        expect.fail("Expected re-issue after an admin revocation to fail");
+      } catch (err) {
+        expect(err.toString()).to.include("VerificationRevokedByAdmin");
+      }
+
+      await program.methods
+        .removeAttester()
+        .accounts({
+          attester: attesterPda,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Links an identity attested by the oracle's Ed25519 signature", async () => {
+      const oracle = Keypair.generate();
+      const IDENTITY_KIND_SOCIAL = 0;
+      const handle = "@test_user_1";
+      const expiresAt = new BN(Math.floor(Date.now() / 1000) + 10 * 60);
+      const [linkedIdentity] = PublicKey.findProgramAddressSync(
+        [Buffer.from("linked_identity"), user1.publicKey.toBuffer(), Buffer.from([IDENTITY_KIND_SOCIAL])],
+        program.programId
+      );
+
+      // Mirrors identity_message in the program
+      const message = Buffer.concat([
+        Buffer.from("social-fi:link-identity:"),
+        user1.publicKey.toBuffer(),
+        Buffer.from([IDENTITY_KIND_SOCIAL]),
+        expiresAt.toArrayLike(Buffer, "le", 8),
+        Buffer.from(handle),
+      ]);
+      const link = (signer: Keypair) =>
+        program.methods
+          .linkIdentity(IDENTITY_KIND_SOCIAL, handle, expiresAt)
+          .accounts({
+            linkedIdentity,
+            owner: user1.publicKey,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .preInstructions([
+            Ed25519Program.createInstructionWithPrivateKey({
+              privateKey: signer.secretKey,
+              message,
+            }),
+          ])
+          .signers([user1])
+          .rpc();
+
+      await program.methods
+        .setIdentityOracle(oracle.publicKey)
+        .accounts({
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      // A valid signature from the wrong key is refused
+      try {
+        await link(Keypair.generate());
+        expect.fail("Expected IdentityOracleMismatch");
+      } catch (err) {
+        expect(err.toString()).to.include("IdentityOracleMismatch");
+      }
+
+      await link(oracle);
+
+      const linked = await program.account.linkedIdentity.fetch(linkedIdentity);
+      expect(linked.wallet.toString()).to.equal(user1.publicKey.toString());
+      expect(linked.handle).to.equal(handle);
+      expect(linked.oracle.toString()).to.equal(oracle.publicKey.toString());
+
+      await program.methods
+        .unlinkIdentity()
+        .accounts({
+          linkedIdentity,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(linkedIdentity)).to.be.null;
+    });
+
+    it("Blocks tips to a blocklisted wallet until it is removed", async () => {
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+      const [blocked] = PublicKey.findProgramAddressSync(
+        [Buffer.from("blocked"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const [user1Profile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const [creatorProfile] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+      const tip = () =>
+        program.methods
+          .sendTip(new BN(0.01 * LAMPORTS_PER_SOL))
+          .accounts({
+            sender: user1.publicKey,
+            senderProfile: user1Profile,
+            recipient: creator.publicKey,
+            recipientProfile: creatorProfile,
+            systemProgram: SystemProgram.programId,
+            feeCollector: provider.wallet.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+      await program.methods
+        .addToBlocklist(creator.publicKey)
+        .accounts({
+          platformConfig,
+          blocked,
+          admin: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await tip();
+        expect.fail("tip to a blocklisted wallet should fail");
+      } catch (e) {
+        expect(e.toString()).to.include("WalletBlocked");
+      }
+
+      await program.methods
+        .removeFromBlocklist()
+        .accounts({
+          platformConfig,
+          blocked,
+          admin: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      await tip();
+    });
+
+    it("Creates a multisig and collects approvals", async () => {
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+      const [multisig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig"), provider.wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const [vault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig_vault"), multisig.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createMultisig([provider.wallet.publicKey, user1.publicKey], 2)
+        .accounts({
+          creator: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      // Admin action signed by the vault once approved
+      const ix = await program.methods
+        .pauseModules(1 << 4)
+        .accounts({
+          platformConfig,
+          admin: vault,
+        })
+        .instruction();
+
+      const [transaction] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig_transaction"), multisig.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .proposeMultisigTransaction(
+          ix.keys.map((key) => ({ pubkey: key.pubkey, isSigner: key.isSigner, isWritable: key.isWritable })),
+          ix.data
+        )
+        .accounts({
+          multisig,
+          transaction,
+          proposer: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .approveMultisigTransaction()
+        .accounts({
+          multisig,
+          transaction,
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const tx = await program.account.multisigTransaction.fetch(transaction);
+      expect(tx.approvals).to.equal(0b11);
+      expect(tx.executed).to.equal(false);
+    });
+
+    it("Rotates multisig signers through an approved transaction", async () => {
+      const [multisig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig"), provider.wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const [vault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig_vault"), multisig.toBuffer()],
+        program.programId
+      );
+      const index = (await program.account.multisig.fetch(multisig)).transactionCount;
+      const [transaction] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig_transaction"), multisig.toBuffer(), index.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      const ix = await program.methods
+        .changeMultisigSigners([provider.wallet.publicKey, user1.publicKey, user2.publicKey], 2)
+        .accounts({
+          multisig,
+          vault,
+        })
+        .instruction();
+
+      await program.methods
+        .proposeMultisigTransaction(
+          ix.keys.map((key) => ({ pubkey: key.pubkey, isSigner: key.isSigner, isWritable: key.isWritable })),
+          ix.data
+        )
+        .accounts({
+          multisig,
+          transaction,
+          proposer: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .approveMultisigTransaction()
+        .accounts({
+          multisig,
+          transaction,
+          signer: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      await program.methods
+        .executeMultisigTransaction()
+        .accounts({
+          multisig,
+          transaction,
+          vault,
+          executor: provider.wallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: multisig, isSigner: false, isWritable: true },
+          { pubkey: program.programId, isSigner: false, isWritable: false },
+        ])
+        .rpc();
+
+      const rotated = await program.account.multisig.fetch(multisig);
+      expect(rotated.signers.length).to.equal(3);
+      expect(rotated.signerSetSeqno).to.equal(1);
+      expect((await program.account.multisigTransaction.fetch(transaction)).executed).to.be.true;
+
+      // The pause proposal from the old signer set can no longer be approved
+      const [stale] = PublicKey.findProgramAddressSync(
+        [Buffer.from("multisig_transaction"), multisig.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      try {
+        await program.methods
+          .approveMultisigTransaction()
+          .accounts({
+            multisig,
+            transaction: stale,
+            signer: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+        expect.fail("Expected StaleMultisigTransaction");
+      } catch (err) {
+        expect(err.toString()).to.include("StaleMultisigTransaction");
+      }
     });
   });
 });